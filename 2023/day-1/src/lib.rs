@@ -0,0 +1,158 @@
+use aoc_core::{Error, ParseError, Solution, Source};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, Error> {
+        solution_part_1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<i32, Error> {
+        solution_part_2(input)
+    }
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    let source = Source::new(input);
+
+    source
+        .lines()
+        .map(|line| {
+            let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+            let first = digits.next().ok_or_else(|| source.error(line, "a digit"))?;
+            let last = digits.next_back().unwrap_or(first);
+
+            Ok((first * 10 + last) as usize)
+        })
+        .sum()
+}
+
+trait DigitsSearcher {
+    fn find_first_digit(self) -> Option<u32>;
+}
+
+impl<I> DigitsSearcher for I
+where
+    I: Iterator<Item = char>,
+{
+    fn find_first_digit(mut self) -> Option<u32> {
+        let mut buff = String::new();
+
+        self.find_map(|c| {
+            c.to_digit(10).or_else(|| {
+                buff.push(c);
+
+                buff.find_spelled_out_digit()
+            })
+        })
+    }
+}
+
+trait IsDigitString {
+    fn find_spelled_out_digit(&self) -> Option<u32>;
+}
+
+impl IsDigitString for String {
+    fn find_spelled_out_digit(&self) -> Option<u32> {
+        if self.ends_with("one") || self.starts_with("eno") {
+            Some(1)
+        } else if self.ends_with("two") || self.starts_with("owt") {
+            Some(2)
+        } else if self.ends_with("three") || self.starts_with("eerht") {
+            Some(3)
+        } else if self.ends_with("four") || self.starts_with("ruof") {
+            Some(4)
+        } else if self.ends_with("five") || self.starts_with("evif") {
+            Some(5)
+        } else if self.ends_with("six") || self.starts_with("xis") {
+            Some(6)
+        } else if self.ends_with("seven") || self.starts_with("neves") {
+            Some(7)
+        } else if self.ends_with("eight") || self.starts_with("thgie") {
+            Some(8)
+        } else if self.ends_with("nine") || self.starts_with("enin") {
+            Some(9)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn solution_part_2(input: &str) -> Result<i32, Error> {
+    let source = Source::new(input);
+
+    source
+        .lines()
+        .map(|line| {
+            let first = line.chars().find_first_digit();
+            let last = line.chars().rev().find_first_digit();
+
+            match first.zip(last) {
+                Some((first, last)) => Ok((first * 10 + last) as i32),
+                None => Err(source.error(line, "a digit or a spelled out digit").into()),
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+        "#
+        .trim();
+
+        assert_eq!(solution_part_1(input), Ok(142));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen 
+        "#
+        .trim();
+
+        assert_eq!(solution_part_2(input), Ok(281));
+    }
+
+    #[test]
+    fn other_numeric_characters_are_not_digits() {
+        assert_eq!(solution_part_1("a½b1"), Ok(11));
+        assert_eq!(solution_part_2("a½b1"), Ok(11));
+        assert!(solution_part_2("a½b").is_err());
+    }
+
+    #[test]
+    fn lines_without_digits_are_rejected() {
+        let error = solution_part_1("1abc2\npqrstu").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "invalid input at line 2, column 1: expected a digit, found `pqrstu`"
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use aoc_core::{Direction, Error, Grid, ParseError, Point, Solution};
+
+/// The sides a tile's pipe opens towards. `S` may hide any pipe.
+fn connections(tile: char) -> &'static [Direction] {
+    match tile {
+        '|' => &[Direction::N, Direction::S],
+        '-' => &[Direction::E, Direction::W],
+        'L' => &[Direction::N, Direction::E],
+        'J' => &[Direction::N, Direction::W],
+        '7' => &[Direction::S, Direction::W],
+        'F' => &[Direction::S, Direction::E],
+        'S' => &Direction::CARDINAL,
+        _ => &[],
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let tiles = Grid::parse_with(input, "a pipe, `.` or `S`", |c| {
+            "|-LJ7F.S".contains(c).then_some(c)
+        })?;
+
+        let start = tiles
+            .position(|&c| c == 'S')
+            .ok_or_else(|| ParseError::at(input, &input[input.len()..], "a start tile `S`"))?;
+
+        Ok(Maze { tiles, start })
+    }
+
+    fn part1(maze: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(maze.find_loop()?.len() / 2)
+    }
+
+    fn part2(maze: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(enclosed_tiles(&maze.find_loop()?))
+    }
+}
+
+pub struct Maze {
+    tiles: Grid<char>,
+    start: Point,
+}
+
+impl Maze {
+    /// Every tile of the loop through `S` in walking order, starting at `S`.
+    pub fn find_loop(&self) -> Result<Vec<Point>, Error> {
+        Direction::CARDINAL
+            .into_iter()
+            .find_map(|heading| self.trace(heading))
+            .ok_or_else(|| Error::NoSolution("no loop passes through the start tile".to_string()))
+    }
+
+    /// Follows the pipes leaving `S` towards `heading`, giving up at a pipe
+    /// that does not connect back.
+    fn trace(&self, mut heading: Direction) -> Option<Vec<Point>> {
+        let mut vertices = vec![self.start];
+        let mut current = self.start;
+
+        loop {
+            let next = (current + heading)?;
+            let tile = *self.tiles.get(next)?;
+            let entry = heading.reverse();
+
+            if !connections(tile).contains(&entry) {
+                return None;
+            }
+
+            if next == self.start {
+                return Some(vertices);
+            }
+
+            heading = *connections(tile)
+                .iter()
+                .find(|&&direction| direction != entry)?;
+            current = next;
+            vertices.push(next);
+        }
+    }
+}
+
+/// Tiles strictly inside the loop, by Pick's theorem over the shoelace area.
+fn enclosed_tiles(vertices: &[Point]) -> usize {
+    let doubled_area = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (a.x * b.y) as i64 - (b.x * a.y) as i64)
+        .sum::<i64>()
+        .unsigned_abs() as usize;
+
+    (doubled_area - vertices.len()) / 2 + 1
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    Day10::part1(&Day10::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    Day10::part2(&Day10::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#"
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(8));
+    }
+
+    #[test]
+    fn part1_1() {
+        let input = r#"
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(4));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."#
+            .trim();
+
+        let squeezed = r#"
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+.........."#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(4));
+        assert_eq!(solution_part_2(squeezed), Ok(4));
+    }
+
+    #[test]
+    fn part2_larger() {
+        let input = r#"
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ..."#
+            .trim();
+
+        let junk = r#"
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L"#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(8));
+        assert_eq!(solution_part_2(junk), Ok(10));
+    }
+
+    #[test]
+    fn loop_is_traced_in_order() {
+        let maze = Day10::parse(".....\n.S-7.\n.|.|.\n.L-J.\n.....").unwrap();
+
+        assert_eq!(
+            maze.find_loop().unwrap()[..3],
+            [Point::new(1, 1), Point::new(2, 1), Point::new(3, 1)]
+        );
+        assert!(matches!(
+            solution_part_1("S-7\n..|"),
+            Err(Error::NoSolution(_))
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+use aoc_core::{Error, Grid, ParseError, Point, Solution};
+
+/// The galaxies in an image, and how many empty rows and columns come before
+/// each row and column so any expansion is a lookup.
+#[derive(Debug)]
+pub struct Universe {
+    galaxies: Vec<Point>,
+    empty_columns_before: Vec<usize>,
+    empty_rows_before: Vec<usize>,
+}
+
+impl Universe {
+    pub fn from_grid(grid: &Grid<char>) -> Universe {
+        Universe {
+            galaxies: grid
+                .iter()
+                .filter(|(_, &c)| c == '#')
+                .map(|(point, _)| point)
+                .collect(),
+            empty_columns_before: empty_before(
+                grid.columns().map(|mut column| column.all(|&c| c != '#')),
+            ),
+            empty_rows_before: empty_before(grid.rows().map(|row| row.iter().all(|&c| c != '#'))),
+        }
+    }
+
+    pub fn galaxies(&self) -> &[Point] {
+        &self.galaxies
+    }
+
+    /// Where the galaxies end up once every empty column is replaced by
+    /// `x_factor` columns and every empty row by `y_factor` rows.
+    pub fn expanded(&self, x_factor: usize, y_factor: usize) -> Vec<Point> {
+        self.galaxies
+            .iter()
+            .map(|galaxy| {
+                let (x_empty, y_empty) = (
+                    self.empty_columns_before[galaxy.x],
+                    self.empty_rows_before[galaxy.y],
+                );
+
+                Point::new(
+                    galaxy.x - x_empty + x_empty * x_factor,
+                    galaxy.y - y_empty + y_empty * y_factor,
+                )
+            })
+            .collect()
+    }
+
+    /// The sum of the distances between every pair of galaxies after expanding.
+    pub fn distance_sum(&self, x_factor: usize, y_factor: usize) -> usize {
+        let galaxies = self.expanded(x_factor, y_factor);
+
+        axis_distance_sum(galaxies.iter().map(|galaxy| galaxy.x).collect())
+            + axis_distance_sum(galaxies.iter().map(|galaxy| galaxy.y).collect())
+    }
+}
+
+/// How many of the lines before each one are empty.
+fn empty_before(empty: impl Iterator<Item = bool>) -> Vec<usize> {
+    empty
+        .scan(0, |count, empty| {
+            let before = *count;
+            *count += empty as usize;
+            Some(before)
+        })
+        .collect()
+}
+
+/// The sum of `|a - b|` over every pair of values. Once sorted, each value is
+/// at least every value before it, so it adds `value * index` minus their sum.
+fn axis_distance_sum(mut values: Vec<usize>) -> usize {
+    values.sort_unstable();
+
+    let mut before = 0;
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, &value)| {
+            let distances = value * index - before;
+            before += value;
+            distances
+        })
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Universe;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let grid = Grid::parse_with(input, "`#` or `.`", |c| "#.".contains(c).then_some(c))?;
+
+        Ok(Universe::from_grid(&grid))
+    }
+
+    fn part1(universe: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(universe.distance_sum(2, 2))
+    }
+
+    fn part2(universe: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(universe.distance_sum(1_000_000, 1_000_000))
+    }
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    Day11::part1(&Day11::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    Day11::part2(&Day11::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    const EXAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solution_part_1(EXAMPLE), Ok(374));
+    }
+
+    #[test]
+    fn part2_smaller_expansions() {
+        let universe = Day11::parse(EXAMPLE).unwrap();
+
+        assert_eq!(universe.distance_sum(10, 10), 1030);
+        assert_eq!(universe.distance_sum(100, 100), 8410);
+    }
+
+    #[test]
+    fn expands_each_axis_by_its_own_factor() {
+        let universe = Day11::parse(EXAMPLE).unwrap();
+
+        // Columns 2, 5 and 8 and rows 3 and 7 are empty.
+        assert_eq!(universe.galaxies()[5], Point::new(9, 6));
+        assert_eq!(universe.expanded(2, 2)[5], Point::new(12, 7));
+        assert_eq!(universe.expanded(10, 1)[5], Point::new(36, 6));
+        assert_eq!(universe.expanded(1, 0)[5], Point::new(9, 5));
+    }
+
+    #[test]
+    fn distance_sum_matches_every_pair() {
+        let universe = Day11::parse(EXAMPLE).unwrap();
+
+        for (x_factor, y_factor) in [(1, 1), (2, 7), (1_000, 3)] {
+            let pairs = universe
+                .expanded(x_factor, y_factor)
+                .into_iter()
+                .tuple_combinations()
+                .map(|(a, b)| a.manhattan(b))
+                .sum();
+
+            assert_eq!(universe.distance_sum(x_factor, y_factor), pairs);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use aoc_core::{Error, ParseError, Solution, Source};
+
+#[derive(Debug)]
+pub struct Game {
+    line: String,
+    batches: Vec<u32>,
+}
+
+impl Game {
+    /// The row repeated `n` times with `?` between copies, and the batches
+    /// repeated to match.
+    pub fn unfold(&self, n: usize) -> Game {
+        Game {
+            line: vec![self.line.as_str(); n].join("?"),
+            batches: self.batches.repeat(n),
+        }
+    }
+
+    /// How many ways the `?` springs can be filled in to match the batches.
+    pub fn possible_solution_count(&self) -> usize {
+        let line = self.line.as_bytes();
+        let (n, m) = (line.len(), self.batches.len());
+
+        // ways[i][j]: arrangements of line[i..] that match batches[j..].
+        let mut ways = vec![vec![0; m + 1]; n + 2];
+        ways[n][m] = 1;
+        ways[n + 1][m] = 1;
+
+        for i in (0..n).rev() {
+            for j in 0..=m {
+                if line[i] != b'#' {
+                    ways[i][j] += ways[i + 1][j];
+                }
+
+                if line[i] == b'.' || j == m {
+                    continue;
+                }
+
+                let end = i + self.batches[j] as usize;
+
+                if end <= n && !line[i..end].contains(&b'.') && line.get(end) != Some(&b'#') {
+                    ways[i][j] += ways[end + 1][j + 1];
+                }
+            }
+        }
+
+        ways[0][0]
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let source = Source::new(input);
+
+        source
+            .lines()
+            .map(|line| {
+                let (first, second) = source.split_once(line, " ")?;
+
+                source.chars(first, "`.`, `#` or `?`", |c| ".#?".contains(c).then_some(c))?;
+
+                let batches = second
+                    .split(",")
+                    .map(|s| source.number::<u32>(s))
+                    .collect::<Result<Vec<u32>, _>>()?;
+
+                Ok(Game {
+                    line: first.to_string(),
+                    batches,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(games
+            .iter()
+            .map(|puzzle| puzzle.possible_solution_count())
+            .sum())
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(games
+            .iter()
+            .map(|puzzle| puzzle.unfold(5).possible_solution_count())
+            .sum())
+    }
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    Day12::part1(&Day12::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    Day12::part2(&Day12::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::*;
+
+    /// Tries every way of filling in the `?` springs.
+    fn brute_force_count(game: &Game) -> usize {
+        let spaces_to_fill = game.line.chars().filter(|c| c == &'?').count();
+
+        repeat_n(['.', '#'], spaces_to_fill)
+            .multi_cartesian_product()
+            .filter(|option| {
+                let mut option_iter = option.iter();
+                let counts = game
+                    .line
+                    .chars()
+                    .map(|c| match c {
+                        '?' => *option_iter.next().unwrap(),
+                        value => value,
+                    })
+                    .group_by(|c| c == &'#')
+                    .into_iter()
+                    .filter_map(|(is_hash, group)| is_hash.then_some(group.count() as u32))
+                    .collect::<Vec<u32>>();
+
+                game.batches == counts
+            })
+            .count()
+    }
+
+    #[test]
+    fn part1_1() {
+        let input = r#"???.### 1,1,3"#.trim();
+
+        assert_eq!(solution_part_1(input), Ok(1));
+    }
+
+    #[test]
+    fn part1_2() {
+        let input = r#".??..??...?##. 1,1,3"#.trim();
+
+        assert_eq!(solution_part_1(input), Ok(4));
+    }
+
+    #[test]
+    fn part1_3() {
+        let input = r#"?#?#?#?#?#?#?#? 1,3,1,6"#.trim();
+
+        assert_eq!(solution_part_1(input), Ok(1));
+    }
+
+    #[test]
+    fn part1_4() {
+        let input = r#"????.#...#... 4,1,1"#.trim();
+
+        assert_eq!(solution_part_1(input), Ok(1));
+    }
+
+    #[test]
+    fn part1_5() {
+        let input = r#"????.######..#####. 1,6,5"#.trim();
+
+        assert_eq!(solution_part_1(input), Ok(4));
+    }
+
+    #[test]
+    fn part1_6() {
+        let input = r#"?###???????? 3,2,1"#.trim();
+
+        assert_eq!(solution_part_1(input), Ok(10));
+    }
+
+    const EXAMPLE: &str = r#"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1"#;
+
+    #[test]
+    fn part2() {
+        assert_eq!(solution_part_2(EXAMPLE), Ok(525152));
+    }
+
+    #[test]
+    fn unfolds_rows_and_batches() {
+        let game = &Day12::parse(".# 1").unwrap()[0];
+        let unfolded = game.unfold(5);
+
+        assert_eq!(unfolded.line, ".#?.#?.#?.#?.#");
+        assert_eq!(unfolded.batches, [1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn counting_agrees_with_brute_force() {
+        for game in Day12::parse(EXAMPLE).unwrap() {
+            for folds in 1..=2 {
+                let game = game.unfold(folds);
+
+                assert_eq!(
+                    game.possible_solution_count(),
+                    brute_force_count(&game),
+                    "{}",
+                    game.line
+                );
+            }
+        }
+    }
+}
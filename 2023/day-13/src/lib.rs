@@ -0,0 +1,258 @@
+use aoc_core::{Error, Grid, ParseError, Point, Solution, Source};
+use itertools::*;
+
+/// The widest or tallest pattern, as its rows and columns are packed into
+/// `u32` masks.
+const MAX_SIDE: usize = 32;
+
+trait ReflectionFinder {
+    /// The first reflection whose two sides differ in exactly `differences`
+    /// cells, trying vertical lines before horizontal ones.
+    fn find_symmetry(&self, differences: u32) -> Option<Symmetry>;
+}
+
+/// A line of reflection after the first `length` masks of a slice, and the
+/// differing cells as `(mask, bit)` indices on the side before the line.
+struct Reflection {
+    length: usize,
+    differences: Vec<(usize, usize)>,
+}
+
+/// Finds a line in `masks`, each `bits` cells wide, across which the masks
+/// differ in exactly `differences` bits.
+fn find_reflection(masks: &[u32], bits: usize, differences: u32) -> Option<Reflection> {
+    (1..masks.len()).find_map(|length| {
+        let pairs = (0..length.min(masks.len() - length))
+            .map(|offset| (length - 1 - offset, length + offset))
+            .collect_vec();
+
+        let count = pairs
+            .iter()
+            .map(|&(a, b)| (masks[a] ^ masks[b]).count_ones())
+            .sum::<u32>();
+
+        (count == differences).then(|| Reflection {
+            length,
+            differences: pairs
+                .iter()
+                .flat_map(|&(a, b)| {
+                    let diff = masks[a] ^ masks[b];
+
+                    (0..bits)
+                        .filter(move |bit| diff & (1 << (bits - 1 - bit)) != 0)
+                        .map(move |bit| (a, bit))
+                })
+                .collect(),
+        })
+    })
+}
+
+#[derive(Debug, PartialEq)]
+enum SymmetryType {
+    Vertical,
+    Horizontal,
+}
+
+#[derive(Debug, PartialEq)]
+struct Symmetry {
+    length: usize,
+    symmetry_type: SymmetryType,
+    /// The cells that have to change for the reflection to be exact.
+    smudges: Vec<Point>,
+}
+
+impl Symmetry {
+    fn summary(&self) -> usize {
+        match self.symmetry_type {
+            SymmetryType::Vertical => self.length,
+            SymmetryType::Horizontal => self.length * 100,
+        }
+    }
+}
+
+impl ReflectionFinder for Grid<char> {
+    fn find_symmetry(&self, differences: u32) -> Option<Symmetry> {
+        let rows = self.rows().map(|row| to_bits(row.iter())).collect_vec();
+        let cols = self.columns().map(to_bits).collect_vec();
+
+        if let Some(reflection) = find_reflection(&cols, self.height(), differences) {
+            return Some(Symmetry {
+                length: reflection.length,
+                symmetry_type: SymmetryType::Vertical,
+                smudges: reflection
+                    .differences
+                    .into_iter()
+                    .map(|(x, y)| Point::new(x, y))
+                    .collect(),
+            });
+        }
+
+        find_reflection(&rows, self.width(), differences).map(|reflection| Symmetry {
+            length: reflection.length,
+            symmetry_type: SymmetryType::Horizontal,
+            smudges: reflection
+                .differences
+                .into_iter()
+                .map(|(y, x)| Point::new(x, y))
+                .collect(),
+        })
+    }
+}
+
+/// Reads `#` as a set bit and `.` as a clear one, most significant first.
+fn to_bits<'a>(cells: impl Iterator<Item = &'a char>) -> u32 {
+    cells.fold(0, |bits, &cell| bits << 1 | u32::from(cell == '#'))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Grid<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let source = Source::new(input);
+
+        input
+            .split("\n\n")
+            .map(|puzzle| {
+                let grid = source.grid(puzzle, "`#` or `.`", |c| "#.".contains(c).then_some(c))?;
+
+                match grid.width() <= MAX_SIDE && grid.height() <= MAX_SIDE {
+                    true => Ok(grid),
+                    false => Err(source.error(
+                        puzzle,
+                        format!("a pattern of at most {} rows of {0} cells", MAX_SIDE),
+                    )),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(puzzles: &Self::Input<'_>) -> Result<usize, Error> {
+        summarise(puzzles, 0)
+    }
+
+    fn part2(puzzles: &Self::Input<'_>) -> Result<usize, Error> {
+        summarise(puzzles, 1)
+    }
+}
+
+/// Sums the summaries of every pattern's reflection with `differences`
+/// smudges, failing if any pattern has none.
+fn summarise(puzzles: &[Grid<char>], differences: u32) -> Result<usize, Error> {
+    puzzles
+        .iter()
+        .enumerate()
+        .map(|(index, puzzle)| {
+            puzzle
+                .find_symmetry(differences)
+                .map(|symmetry| symmetry.summary())
+                .ok_or_else(|| {
+                    Error::NoSolution(format!("pattern {} has no line of reflection", index + 1))
+                })
+        })
+        .sum()
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    Day13::part1(&Day13::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    Day13::part2(&Day13::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_vertical() {
+        let input = r#"
+#.##..##.
+..#.##.#.
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#."#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(5));
+    }
+
+    #[test]
+    fn part1_horizontal() {
+        let input = r#"
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(400));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(400));
+    }
+
+    #[test]
+    fn patterns_without_a_reflection_have_no_solution() {
+        assert!(matches!(
+            solution_part_1("#.##..##.\n..#.##.#.\n\n#.\n.#"),
+            Err(Error::NoSolution(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_patterns_too_wide_for_a_mask() {
+        let input = format!("{0}\n{0}", "#.".repeat(17));
+
+        assert!(matches!(solution_part_1(&input), Err(Error::Parse(_))));
+        assert!(solution_part_1(&format!("{0}\n{0}", "#.".repeat(16))).is_ok());
+    }
+
+    #[test]
+    fn finds_the_smudge() {
+        let puzzles = Day13::parse(
+            "#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.",
+        )
+        .unwrap();
+
+        assert_eq!(
+            puzzles[0].find_symmetry(1),
+            Some(Symmetry {
+                length: 3,
+                symmetry_type: SymmetryType::Horizontal,
+                smudges: vec![Point::new(0, 0)],
+            })
+        );
+    }
+}
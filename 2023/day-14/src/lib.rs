@@ -0,0 +1,136 @@
+use aoc_core::cycle::CycleDetector;
+use aoc_core::{Direction, Error, Grid, ParseError, Solution};
+pub use platform::Platform;
+
+pub mod platform;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Platform;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let grid = Grid::parse_with(input, "`O`, `#` or `.`", |c| "O#.".contains(c).then_some(c))?;
+
+        Platform::from_grid(&grid).ok_or_else(|| {
+            ParseError::at(
+                input,
+                input,
+                format!("at most {} rows of {0} tiles", platform::MAX_SIDE),
+            )
+        })
+    }
+
+    fn part1(platform: &Self::Input<'_>) -> Result<usize, Error> {
+        let mut platform = platform.clone();
+
+        platform.tilt(Direction::N);
+
+        Ok(platform.total_load())
+    }
+
+    fn part2(platform: &Self::Input<'_>) -> Result<usize, Error> {
+        // Brent's algorithm keeps two platforms at a time, and comparing bitsets is cheap.
+        let nth = CycleDetector::Brent.nth_state(platform.clone(), Platform::spin, 1_000_000_000);
+
+        Ok(nth.state.total_load())
+    }
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    Day14::part1(&Day14::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    Day14::part2(&Day14::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::cycle::{self, Cycle};
+
+    const EXAMPLE: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn part1_vertical() {
+        let input = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#
+        .trim();
+
+        assert_eq!(solution_part_1(input), Ok(136));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."#;
+
+        assert_eq!(solution_part_2(input), Ok(64));
+    }
+
+    #[test]
+    fn spins_like_the_puzzle() {
+        let platform = Day14::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            platform.spin().to_string(),
+            ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n\
+             .O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#...."
+        );
+    }
+
+    #[test]
+    fn the_example_settles_into_a_cycle_of_seven() {
+        let platform = Day14::parse(EXAMPLE).unwrap();
+
+        let n = 1_000_000_000;
+
+        for nth in [
+            cycle::nth_state(platform.clone(), Platform::spin, n),
+            CycleDetector::Floyd.nth_state(platform.clone(), Platform::spin, n),
+            CycleDetector::Brent.nth_state(platform.clone(), Platform::spin, n),
+        ] {
+            assert_eq!(
+                nth.cycle,
+                Some(Cycle {
+                    prefix_len: 3,
+                    cycle_len: 7
+                })
+            );
+            assert_eq!(nth.state.total_load(), 64);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use aoc_core::{Direction, Grid, Point};
+use std::fmt;
+
+/// The widest or tallest platform a [`Platform`] can hold, one bit per tile.
+pub const MAX_SIDE: usize = 128;
+
+/// The platform as bitsets: round stones by row, and the cube rocks by row and
+/// by column so either axis can be tilted without looking them up again.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    width: usize,
+    height: usize,
+    rounds: Vec<u128>,
+    cube_rows: Vec<u128>,
+    cube_columns: Vec<u128>,
+}
+
+impl Platform {
+    /// `None` if the grid is wider or taller than [`MAX_SIDE`].
+    pub fn from_grid(grid: &Grid<char>) -> Option<Platform> {
+        if grid.width() > MAX_SIDE || grid.height() > MAX_SIDE {
+            return None;
+        }
+
+        let bits = |c: char| {
+            grid.rows()
+                .map(move |row| to_bits(row.iter().map(|&tile| tile == c)))
+        };
+        let cube_rows = bits('#').collect::<Vec<_>>();
+
+        Some(Platform {
+            width: grid.width(),
+            height: grid.height(),
+            rounds: bits('O').collect(),
+            cube_columns: transpose(&cube_rows, grid.width()),
+            cube_rows,
+        })
+    }
+
+    /// Rolls every round stone as far towards `direction` as it goes.
+    ///
+    /// # Panics
+    ///
+    /// If `direction` is diagonal.
+    pub fn tilt(&mut self, direction: Direction) {
+        match direction {
+            Direction::W | Direction::E => {
+                for (rounds, &cubes) in self.rounds.iter_mut().zip(&self.cube_rows) {
+                    *rounds = tilt_line(*rounds, cubes, self.width, direction == Direction::W);
+                }
+            }
+            Direction::N | Direction::S => {
+                let columns = transpose(&self.rounds, self.width)
+                    .into_iter()
+                    .zip(&self.cube_columns)
+                    .map(|(rounds, &cubes)| {
+                        tilt_line(rounds, cubes, self.height, direction == Direction::N)
+                    })
+                    .collect::<Vec<_>>();
+
+                self.rounds = transpose(&columns, self.height);
+            }
+            _ => panic!("a platform only tilts north, east, south or west"),
+        }
+    }
+
+    /// Tilts north, west, south and then east.
+    pub fn spin(&self) -> Platform {
+        let mut platform = self.clone();
+
+        for direction in [Direction::N, Direction::W, Direction::S, Direction::E] {
+            platform.tilt(direction);
+        }
+
+        platform
+    }
+
+    /// The load on the north support beams.
+    pub fn total_load(&self) -> usize {
+        self.rounds
+            .iter()
+            .enumerate()
+            .map(|(y, row)| row.count_ones() as usize * (self.height - y))
+            .sum()
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut grid = Grid::new(self.width, self.height, '.');
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let bit = 1 << x;
+
+                if self.rounds[y] & bit != 0 {
+                    grid[Point::new(x, y)] = 'O';
+                } else if self.cube_rows[y] & bit != 0 {
+                    grid[Point::new(x, y)] = '#';
+                }
+            }
+        }
+
+        write!(f, "{grid}")
+    }
+}
+
+fn to_bits(tiles: impl Iterator<Item = bool>) -> u128 {
+    tiles
+        .enumerate()
+        .filter(|&(_, set)| set)
+        .fold(0, |bits, (index, _)| bits | 1 << index)
+}
+
+/// `length` low bits set, for any length up to 128.
+fn ones(length: usize) -> u128 {
+    u128::MAX.checked_shr(128 - length as u32).unwrap_or(0)
+}
+
+/// Turns lines of bits into one line per bit position, `length` of them.
+fn transpose(lines: &[u128], length: usize) -> Vec<u128> {
+    let mut transposed = vec![0; length];
+
+    for (index, &line) in lines.iter().enumerate() {
+        let mut bits = line;
+
+        while bits != 0 {
+            transposed[bits.trailing_zeros() as usize] |= 1 << index;
+            bits &= bits - 1;
+        }
+    }
+
+    transposed
+}
+
+/// Packs the round stones between each pair of cube rocks against the
+/// lower-index end of their gap if `towards_start`, else the higher one.
+fn tilt_line(rounds: u128, cubes: u128, length: usize, towards_start: bool) -> u128 {
+    let mut tilted = 0;
+    let mut start = 0;
+
+    while start < length {
+        let end = match cubes & !ones(start) {
+            0 => length,
+            rest => (rest.trailing_zeros() as usize).min(length),
+        };
+
+        let count = (rounds & ones(end) & !ones(start)).count_ones() as usize;
+        let offset = if towards_start { start } else { end - count };
+
+        if count > 0 {
+            tilted |= ones(count) << offset;
+        }
+
+        start = end + 1;
+    }
+
+    tilted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tilts_a_line_both_ways() {
+        // .O.#O.O. from bit 0 upwards
+        let (rounds, cubes) = (0b0101_0010, 0b0000_1000);
+
+        assert_eq!(tilt_line(rounds, cubes, 8, true), 0b0011_0001);
+        assert_eq!(tilt_line(rounds, cubes, 8, false), 0b1100_0100);
+        assert_eq!(tilt_line(u128::MAX, 0, 128, false), u128::MAX);
+        assert_eq!(tilt_line(1, 0, 128, false), 1 << 127);
+        assert_eq!(tilt_line(1, 1 << 127, 128, false), 1 << 126);
+    }
+}
@@ -1,33 +1,40 @@
-use itertools::*;
-use std::time::Instant;
+use aoc_core::{Error, ParseError, Solution, Source};
 
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
+pub struct Day15;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = solution_part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
 
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let input = std::fs::read_to_string("input.txt").unwrap();
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
 
-    let start_part_2 = Instant::now();
-    let part_2_result = solution_part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+    fn part1(input: &Self::Input<'_>) -> Result<usize, Error> {
+        solution_part_1(input)
+    }
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
+    fn part2(input: &Self::Input<'_>) -> Result<usize, Error> {
+        solution_part_2(input)
+    }
 }
 
 fn christmas_hash(s: &str) -> u32 {
     s.chars().fold(0, |acc, c| {
-        let result = ((acc as u32) + (c as u32)) * 17;
+        let result = (acc + (c as u32)) * 17;
         result % 256
     })
 }
 
-fn solution_part_1(input: &str) -> usize {
-    input.split(',').map(christmas_hash).sum::<u32>() as usize
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    Ok(input
+        .split(',')
+        .map(|step| christmas_hash(step.trim()))
+        .sum::<u32>() as usize)
 }
 
 #[derive(Debug)]
@@ -54,7 +61,7 @@ struct Box {
     lenses: Vec<Lens>,
 }
 
-fn calculate_focal_power(boxes: &Vec<Box>) -> usize {
+fn calculate_focal_power(boxes: &[Box]) -> usize {
     boxes.iter().enumerate().fold(0, |acc, (idx, b)| {
         let box_power = idx + 1;
 
@@ -70,34 +77,33 @@ fn calculate_focal_power(boxes: &Vec<Box>) -> usize {
     })
 }
 
-fn solution_part_2(input: &str) -> usize {
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    let source = Source::new(input);
+
     let instructions = input
         .split(',')
         .map(|raw_instruction| {
             let instruction = raw_instruction.trim();
 
-            if instruction.chars().last().unwrap_or('.') == '-' {
-                let (lens_label, _) = instruction.split_once('-').unwrap();
-                let box_number = christmas_hash(&lens_label);
-
-                return Instruction {
+            if let Some(lens_label) = instruction.strip_suffix('-') {
+                return Ok(Instruction {
                     lens_label: lens_label.to_string(),
-                    box_number,
+                    box_number: christmas_hash(lens_label),
                     operation: Operation::Remove,
-                };
+                });
             }
 
-            let (lens_label, focal_length) = instruction.split_once('=').unwrap();
-            let focal_length = focal_length.parse::<u32>().unwrap();
-            let box_number = christmas_hash(&lens_label);
+            let (lens_label, focal_length) = instruction.split_once('=').ok_or_else(|| {
+                source.error(instruction, "`<label>=<focal length>` or `<label>-`")
+            })?;
 
-            Instruction {
+            Ok(Instruction {
                 lens_label: lens_label.to_string(),
-                box_number,
-                operation: Operation::AddOrReplace(focal_length),
-            }
+                box_number: christmas_hash(lens_label),
+                operation: Operation::AddOrReplace(source.number(focal_length)?),
+            })
         })
-        .collect_vec();
+        .collect::<Result<Vec<_>, ParseError>>()?;
 
     let mut boxes = std::iter::repeat_with(|| Box { lenses: Vec::new() })
         .take(256)
@@ -134,7 +140,7 @@ fn solution_part_2(input: &str) -> usize {
         }
     }
 
-    calculate_focal_power(&boxes)
+    Ok(calculate_focal_power(&boxes))
 }
 
 #[cfg(test)]
@@ -145,7 +151,14 @@ mod tests {
     fn part1() {
         let input = r#"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"#.trim();
 
-        assert_eq!(solution_part_1(input), 1320);
+        assert_eq!(solution_part_1(input), Ok(1320));
+    }
+
+    #[test]
+    fn part1_ignores_the_trailing_newline() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+        assert_eq!(solution_part_1(input), Ok(1320));
     }
 
     #[test]
@@ -159,13 +172,14 @@ mod tests {
     fn part2() {
         let input = r#"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"#.trim();
 
-        assert_eq!(solution_part_2(input), 145);
+        assert_eq!(solution_part_2(input), Ok(145));
     }
 
     #[test]
-    fn part2_real() {
-        let input = std::fs::read_to_string("input.txt").unwrap();
-
-        assert_eq!(solution_part_2(&input), 279470);
+    fn operations_without_focal_length_are_rejected() {
+        assert_eq!(
+            solution_part_2("rn=1,cm=x").unwrap_err().to_string(),
+            "invalid input at line 1, column 9: expected a number, found `x`"
+        );
     }
 }
@@ -0,0 +1,176 @@
+use aoc_core::{Direction, Error, Grid, ParseError, Point, Solution};
+use rayon::prelude::*;
+
+/// The directions a beam leaves `tile` in after entering it heading `direction`.
+fn deflect(tile: char, direction: Direction) -> [Option<Direction>; 2] {
+    match (tile, direction) {
+        ('|', Direction::E | Direction::W) => [Some(Direction::N), Some(Direction::S)],
+        ('-', Direction::N | Direction::S) => [Some(Direction::E), Some(Direction::W)],
+        ('/', Direction::N | Direction::S) => [Some(direction.turn_right()), None],
+        ('/', _) => [Some(direction.turn_left()), None],
+        ('\\', Direction::N | Direction::S) => [Some(direction.turn_left()), None],
+        ('\\', _) => [Some(direction.turn_right()), None],
+        _ => [Some(direction), None],
+    }
+}
+
+/// Which tiles the beam crosses, as a bitset per tile with one bit for each
+/// direction it crossed that tile in.
+fn trace(grid: &Grid<char>, start: Point, direction: Direction) -> Grid<u8> {
+    let mut visited = Grid::new(grid.width(), grid.height(), 0_u8);
+    let mut beams = vec![(start, direction)];
+
+    while let Some((position, direction)) = beams.pop() {
+        let bit = 1 << direction as u8;
+
+        if !grid.contains(position) || visited[position] & bit != 0 {
+            continue;
+        }
+
+        visited[position] |= bit;
+
+        for next in deflect(grid[position], direction).into_iter().flatten() {
+            beams.extend((position + next).map(|position| (position, next)));
+        }
+    }
+
+    visited
+}
+
+/// How many beams cross each tile when one enters at `start` heading
+/// `direction`, counting each direction once. Printing the grid draws a heat map.
+pub fn energy(grid: &Grid<char>, start: Point, direction: Direction) -> Grid<u32> {
+    trace(grid, start, direction).map(|bits| bits.count_ones())
+}
+
+fn energised_tiles(grid: &Grid<char>, start: Point, direction: Direction) -> usize {
+    trace(grid, start, direction)
+        .iter()
+        .filter(|(_, &bits)| bits != 0)
+        .count()
+}
+
+/// Every tile on the edge together with the heading a beam enters it with.
+fn edge_entries(grid: &Grid<char>) -> Vec<(Point, Direction)> {
+    let (width, height) = (grid.width(), grid.height());
+
+    (0..width)
+        .flat_map(|x| {
+            [
+                (Point::new(x, 0), Direction::S),
+                (Point::new(x, height - 1), Direction::N),
+            ]
+        })
+        .chain((0..height).flat_map(|y| {
+            [
+                (Point::new(0, y), Direction::E),
+                (Point::new(width - 1, y), Direction::W),
+            ]
+        }))
+        .collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_with(input, "`.`, `|`, `-`, `/` or `\\`", |c| {
+            ".|-/\\".contains(c).then_some(c)
+        })
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(energised_tiles(grid, Point::new(0, 0), Direction::E))
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(edge_entries(grid)
+            .into_par_iter()
+            .map(|(start, direction)| energised_tiles(grid, start, direction))
+            .max()
+            .unwrap_or(0))
+    }
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    Day16::part1(&Day16::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    Day16::part2(&Day16::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(46));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(51));
+    }
+
+    #[test]
+    fn energy_maps_every_crossing() {
+        // The beam loops round and crosses the centre tile a second time.
+        let grid = Day16::parse(".\\.\n/..\n\\/.").unwrap();
+
+        assert_eq!(
+            energy(&grid, Point::new(0, 0), Direction::E).to_string(),
+            "110\n121\n110"
+        );
+    }
+
+    #[test]
+    fn long_beams_do_not_overflow_the_stack() {
+        // Mirrors at both ends of every row send the beam snaking down the
+        // whole grid, one tile at a time.
+        let size = 500;
+        let grid = (0..size)
+            .map(|y| {
+                let end = if y % 2 == 0 { '\\' } else { '/' };
+                let start = if y == 0 { '.' } else { end };
+
+                format!("{start}{}{end}", ".".repeat(size - 2))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(solution_part_1(&grid), Ok(size * size));
+    }
+}
@@ -0,0 +1,49 @@
+//! Times the native search against the A* it replaced on the bundled input:
+//! `cargo bench -p day-17`.
+
+use aoc_core::bench::{measure, BenchConfig};
+use aoc_core::solution::Outcome;
+use aoc_core::{Answer, Error, Solution};
+use day_17::{find_route, CrucibleRules, Day17};
+use std::time::{Duration, Instant};
+
+#[path = "../src/reference/mod.rs"]
+mod reference;
+
+fn main() -> Result<(), Error> {
+    let input = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))
+        .expect("day 17's input.txt to be readable");
+    let grid = Day17::parse(input.trim())?;
+    let config = BenchConfig::default();
+
+    for rules in [CrucibleRules::CRUCIBLE, CrucibleRules::ULTRA_CRUCIBLE] {
+        let native = measure(&config, || {
+            timed(|| Ok(find_route(&grid, rules)?.heat_loss))
+        })?;
+        let astar = measure(&config, || {
+            timed(|| {
+                reference::astar_heat_loss(&grid, rules)
+                    .ok_or_else(|| Error::NoSolution("astar found no route".to_string()))
+            })
+        })?;
+
+        assert_eq!(native.answer, astar.answer, "{rules:?}");
+        println!(
+            "{rules:?}: native {:?}, astar {:?} (medians of {} and {} runs)",
+            native.solve.median, astar.solve.median, native.samples, astar.samples
+        );
+    }
+
+    Ok(())
+}
+
+fn timed(solve: impl FnOnce() -> Result<u32, Error>) -> Result<Outcome, Error> {
+    let started = Instant::now();
+    let answer = solve()?;
+
+    Ok(Outcome {
+        answer: Answer::from(answer),
+        parse_time: Duration::ZERO,
+        solve_time: started.elapsed(),
+    })
+}
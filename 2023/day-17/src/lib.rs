@@ -0,0 +1,214 @@
+use aoc_core::{Direction, Error, Grid, ParseError, Point, Solution};
+
+#[cfg(test)]
+mod reference;
+mod search;
+
+/// How far a crucible must and may travel in a straight line, and whether it
+/// may turn straight back the way it came.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrucibleRules {
+    pub min_straight: u32,
+    pub max_straight: u32,
+    pub can_reverse: bool,
+}
+
+impl CrucibleRules {
+    /// The rules for part 1.
+    pub const CRUCIBLE: CrucibleRules = CrucibleRules {
+        min_straight: 1,
+        max_straight: 3,
+        can_reverse: false,
+    };
+
+    /// The rules for part 2.
+    pub const ULTRA_CRUCIBLE: CrucibleRules = CrucibleRules {
+        min_straight: 4,
+        max_straight: 10,
+        can_reverse: false,
+    };
+}
+
+/// The cheapest route from the top left block to the bottom right one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub heat_loss: u32,
+    /// Every block visited, starting with the top left one.
+    pub path: Vec<Point>,
+}
+
+impl Route {
+    /// The grid with an arrow on every block the route enters, showing which
+    /// way it moved, as drawn in the puzzle.
+    pub fn render(&self, grid: &Grid<u32>) -> String {
+        let mut cells = grid.map(|heat_loss| char::from_digit(*heat_loss, 10).unwrap_or('?'));
+
+        for pair in self.path.windows(2) {
+            let direction = Direction::CARDINAL
+                .into_iter()
+                .find(|&direction| pair[0] + direction == Some(pair[1]));
+
+            cells[pair[1]] = match direction {
+                Some(Direction::N) => '^',
+                Some(Direction::E) => '>',
+                Some(Direction::S) => 'v',
+                _ => '<',
+            };
+        }
+
+        cells.to_string()
+    }
+}
+
+/// The route that loses the least heat under `rules`.
+pub fn find_route(grid: &Grid<u32>, rules: CrucibleRules) -> Result<Route, Error> {
+    search::cheapest_route(grid, rules)
+        .ok_or_else(|| Error::NoSolution("no route reaches the bottom right block".to_string()))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let grid = Grid::parse_with(input, "a digit", |c| c.to_digit(10))?;
+
+        if grid.width() == 0 || grid.height() == 0 {
+            return Err(ParseError::at(input, input, "a row of digits"));
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(find_route(grid, CrucibleRules::CRUCIBLE)?.heat_loss as usize)
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Result<usize, Error> {
+        Ok(find_route(grid, CrucibleRules::ULTRA_CRUCIBLE)?.heat_loss as usize)
+    }
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    Day17::part1(&Day17::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    Day17::part2(&Day17::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reference::astar_heat_loss;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solution_part_1(EXAMPLE), Ok(102));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solution_part_2(EXAMPLE), Ok(94));
+    }
+
+    #[test]
+    fn renders_the_route() {
+        let grid = Day17::parse("19\n11").unwrap();
+        let route = find_route(&grid, CrucibleRules::CRUCIBLE).unwrap();
+
+        assert_eq!(route.heat_loss, 2);
+        assert_eq!(route.render(&grid), "19\nv>");
+    }
+
+    #[test]
+    fn reversing_can_make_a_route_possible() {
+        // Runs must be 2 or 3 blocks long, so reaching the fifth block takes
+        // three forward, two back and three forward again.
+        let grid = Day17::parse("11111").unwrap();
+        let rules = CrucibleRules {
+            min_straight: 2,
+            max_straight: 3,
+            can_reverse: false,
+        };
+
+        assert!(matches!(
+            find_route(&grid, rules),
+            Err(Error::NoSolution(_))
+        ));
+
+        let route = find_route(
+            &grid,
+            CrucibleRules {
+                can_reverse: true,
+                ..rules
+            },
+        )
+        .unwrap();
+        assert_eq!(route.heat_loss, 8);
+        assert_eq!(route.path.len(), 9);
+    }
+
+    #[test]
+    fn routes_add_up_to_their_heat_loss() {
+        let grid = Day17::parse(EXAMPLE).unwrap();
+
+        for rules in [CrucibleRules::CRUCIBLE, CrucibleRules::ULTRA_CRUCIBLE] {
+            let route = find_route(&grid, rules).unwrap();
+
+            assert!(route
+                .path
+                .windows(2)
+                .all(|pair| pair[0].manhattan(pair[1]) == 1));
+            assert_eq!(
+                route.path[1..]
+                    .iter()
+                    .map(|&point| grid[point])
+                    .sum::<u32>(),
+                route.heat_loss
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_astar(
+            rows in prop::collection::vec("[1-9]{8}", 1..8),
+            width in 1_usize..=8,
+            min_straight in 0_u32..4,
+            max_straight in 0_u32..6,
+            can_reverse: bool,
+        ) {
+            let input = rows.iter().map(|row| &row[..width]).collect::<Vec<_>>();
+
+            let grid = Day17::parse(&input.join("\n")).unwrap();
+            let rules = CrucibleRules { min_straight, max_straight, can_reverse };
+
+            prop_assert_eq!(
+                find_route(&grid, rules).ok().map(|route| route.heat_loss),
+                astar_heat_loss(&grid, rules)
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// The tests and `benches/astar.rs` share this file, the bench by path.
+
+use super::CrucibleRules;
+use aoc_core::{Direction, Grid, Point};
+use pathfinding::prelude::astar;
+
+#[derive(Eq, PartialEq, Hash, Clone, Debug, Copy)]
+struct JourneyNode {
+    coords: Point,
+    direction: Option<Direction>,
+    direction_count: u32,
+}
+
+/// The states one block on from `state` that `rules` allow.
+fn successors(
+    state: &JourneyNode,
+    grid: &Grid<u32>,
+    rules: CrucibleRules,
+) -> Vec<(JourneyNode, u32)> {
+    Direction::CARDINAL
+        .into_iter()
+        .filter(|&direction| match state.direction {
+            None => true,
+            Some(heading) if state.direction_count < rules.min_straight => direction == heading,
+            Some(heading) => rules.can_reverse || direction != heading.reverse(),
+        })
+        .flat_map(|direction| {
+            let (coords, heat_loss) = step(grid, state.coords, direction)?;
+            let direction_count = match state.direction == Some(direction) {
+                true => state.direction_count + 1,
+                false => 1,
+            };
+
+            (direction_count <= rules.max_straight).then_some((
+                JourneyNode {
+                    coords,
+                    direction: Some(direction),
+                    direction_count,
+                },
+                heat_loss,
+            ))
+        })
+        .collect()
+}
+
+/// The block one step from `point` and its heat loss, if it is on the grid.
+fn step(grid: &Grid<u32>, point: Point, direction: Direction) -> Option<(Point, u32)> {
+    let next = (point + direction)?;
+
+    grid.get(next).map(|&heat_loss| (next, heat_loss))
+}
+
+/// The block-by-block A* search the solver used before, kept to check
+/// and time the native one against.
+pub(crate) fn astar_heat_loss(grid: &Grid<u32>, rules: CrucibleRules) -> Option<u32> {
+    let start = JourneyNode {
+        coords: Point::new(0, 0),
+        direction: None,
+        direction_count: 0,
+    };
+    let end = Point::new(grid.width() - 1, grid.height() - 1);
+
+    astar(
+        &start,
+        |state| successors(state, grid, rules),
+        |state| end.manhattan(state.coords) as u32,
+        |state| {
+            state.coords == end
+                && (state.direction.is_none() || state.direction_count >= rules.min_straight)
+        },
+    )
+    .map(|(_, heat_loss)| heat_loss)
+}
@@ -0,0 +1,153 @@
+use crate::{CrucibleRules, Route};
+use aoc_core::{Direction, Grid, Point};
+
+/// Marks a state reached straight from the start, which has no heading.
+const FROM_START: usize = usize::MAX;
+
+/// Dijkstra's algorithm over `(block, heading)` states, where each edge is a
+/// whole straight run of `min..=max` blocks followed by a turn.
+///
+/// Every run costs at most `9 * max` so the frontier never spans more
+/// distances than that, and a ring of that many buckets serves as the
+/// priority queue (Dial's algorithm).
+pub(crate) fn cheapest_route(grid: &Grid<u32>, rules: CrucibleRules) -> Option<Route> {
+    let start = Point::new(0, 0);
+    let end = Point::new(grid.width() - 1, grid.height() - 1);
+
+    if start == end {
+        return Some(Route {
+            heat_loss: 0,
+            path: vec![start],
+        });
+    }
+
+    let longest = grid.width().max(grid.height()) - 1;
+    let max = (rules.max_straight as usize).min(longest);
+    let min = (rules.min_straight as usize).max(1);
+
+    let mut search = Search {
+        grid,
+        min,
+        max,
+        best: vec![u32::MAX; grid.width() * grid.height() * 4],
+        previous: vec![FROM_START; grid.width() * grid.height() * 4],
+        buckets: vec![vec![]; 9 * max + 1],
+        pending: 0,
+    };
+
+    for direction in Direction::CARDINAL {
+        search.runs(start, direction, 0, FROM_START);
+    }
+
+    let mut distance = 0;
+
+    while search.pending > 0 {
+        let ring = search.buckets.len();
+
+        while let Some(state) = search.buckets[distance as usize % ring].pop() {
+            search.pending -= 1;
+
+            if search.best[state] != distance {
+                continue;
+            }
+
+            let (point, heading) = search.unpack(state);
+
+            if point == end {
+                return Some(search.route(state));
+            }
+
+            for direction in [heading.turn_left(), heading.turn_right(), heading.reverse()]
+                .into_iter()
+                .filter(|&direction| rules.can_reverse || direction != heading.reverse())
+            {
+                search.runs(point, direction, distance, state);
+            }
+        }
+
+        distance += 1;
+    }
+
+    None
+}
+
+struct Search<'a> {
+    grid: &'a Grid<u32>,
+    min: usize,
+    max: usize,
+    best: Vec<u32>,
+    previous: Vec<usize>,
+    buckets: Vec<Vec<usize>>,
+    pending: usize,
+}
+
+impl Search<'_> {
+    fn pack(&self, point: Point, heading: Direction) -> usize {
+        // The cardinal directions are every other variant, from N = 0.
+        (point.y * self.grid.width() + point.x) * 4 + heading as usize / 2
+    }
+
+    fn unpack(&self, state: usize) -> (Point, Direction) {
+        let block = state / 4;
+        let point = Point::new(block % self.grid.width(), block / self.grid.width());
+
+        (point, Direction::CARDINAL[state % 4])
+    }
+
+    /// Relaxes every run of `min..=max` blocks from `from` heading `direction`.
+    fn runs(&mut self, from: Point, direction: Direction, distance: u32, state: usize) {
+        let mut point = from;
+        let mut heat_loss = distance;
+
+        for length in 1..=self.max {
+            let Some(next) = (point + direction).filter(|&next| self.grid.contains(next)) else {
+                return;
+            };
+
+            point = next;
+            heat_loss += self.grid[point];
+
+            if length < self.min {
+                continue;
+            }
+
+            let next = self.pack(point, direction);
+
+            if heat_loss < self.best[next] {
+                self.best[next] = heat_loss;
+                self.previous[next] = state;
+                let ring = self.buckets.len();
+                self.buckets[heat_loss as usize % ring].push(next);
+                self.pending += 1;
+            }
+        }
+    }
+
+    /// Walks the runs back from `state` and lists every block on the way.
+    fn route(&self, state: usize) -> Route {
+        let mut corners = vec![];
+        let mut corner = state;
+
+        while corner != FROM_START {
+            corners.push(corner);
+            corner = self.previous[corner];
+        }
+
+        let mut point = Point::new(0, 0);
+        let mut path = vec![point];
+
+        for &corner in corners.iter().rev() {
+            let (end, heading) = self.unpack(corner);
+
+            while point != end {
+                point = (point + heading).unwrap();
+                path.push(point);
+            }
+        }
+
+        Route {
+            heat_loss: self.best[state],
+            path,
+        }
+    }
+}
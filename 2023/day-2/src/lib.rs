@@ -0,0 +1,157 @@
+use aoc_core::{Error, ParseError, Solution, Source};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let source = Source::new(input);
+
+        source
+            .lines()
+            .map(|line| parse_game(&source, line))
+            .collect()
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Result<i32, Error> {
+        let constrained_balls = Balls {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        Ok(games
+            .iter()
+            .filter(|game| {
+                game.rounds
+                    .iter()
+                    .all(|round| round.fit_in_constraint(&constrained_balls))
+            })
+            .map(|game| game.id)
+            .sum())
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Result<i32, Error> {
+        Ok(games
+            .iter()
+            .map(|game| {
+                let minimum = game
+                    .rounds
+                    .iter()
+                    .fold(Balls::default(), |acc, round| Balls {
+                        blue: acc.blue.max(round.blue),
+                        red: acc.red.max(round.red),
+                        green: acc.green.max(round.green),
+                    });
+
+                minimum.blue * minimum.red * minimum.green
+            })
+            .sum())
+    }
+}
+
+#[derive(Debug, Default)]
+struct Balls {
+    blue: i32,
+    red: i32,
+    green: i32,
+}
+
+impl Balls {
+    fn fit_in_constraint(&self, constraints: &Balls) -> bool {
+        self.blue <= constraints.blue
+            && self.red <= constraints.red
+            && self.green <= constraints.green
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: i32,
+    rounds: Vec<Balls>,
+}
+
+fn parse_game(source: &Source, line: &str) -> Result<Game, ParseError> {
+    let (game, rounds) = source.split_once(line, ": ")?;
+    let id = source.number(source.strip_prefix(game, "Game ")?)?;
+
+    let rounds = rounds
+        .split("; ")
+        .map(|round| {
+            let mut balls = Balls::default();
+
+            for draw in round.split(", ") {
+                let (count, colour) = source.split_once(draw, " ")?;
+                let count = source.number(count)?;
+
+                match colour {
+                    "blue" => balls.blue = count,
+                    "red" => balls.red = count,
+                    "green" => balls.green = count,
+                    _ => return Err(source.error(colour, "`red`, `green` or `blue`")),
+                }
+            }
+
+            Ok(balls)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, rounds })
+}
+
+pub fn solution_part_1(input: &str) -> Result<i32, Error> {
+    Day2::part1(&Day2::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<i32, Error> {
+    Day2::part2(&Day2::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#
+        .trim();
+
+        assert_eq!(solution_part_1(input), Ok(8));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#
+        .trim();
+
+        assert_eq!(solution_part_2(input), Ok(2286));
+    }
+
+    #[test]
+    fn unknown_colours_are_rejected() {
+        let error = Day2::parse("Game 1: 3 blue\nGame 2: 4 gren").unwrap_err();
+
+        assert_eq!(
+            (error.line, error.column, error.found.as_str()),
+            (2, 11, "gren")
+        );
+    }
+}
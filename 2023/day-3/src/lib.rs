@@ -0,0 +1,151 @@
+use aoc_core::{Error, Grid, ParseError, Point, Solution};
+use std::collections::{HashMap, HashSet};
+
+trait SpecialCharactersChecker {
+    fn is_engine_part(&self) -> bool;
+    fn is_gear_part(&self) -> bool;
+}
+
+impl SpecialCharactersChecker for char {
+    fn is_engine_part(&self) -> bool {
+        if self.is_ascii_digit() {
+            return false;
+        }
+
+        !matches!(self, '.')
+    }
+
+    fn is_gear_part(&self) -> bool {
+        self.eq(&'*')
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Grid<char>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_with(input, "a digit, `.` or a symbol", |c| {
+            (!c.is_whitespace()).then_some(c)
+        })
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<i32, Error> {
+        Ok(part_numbers(grid)
+            .filter(|number| !number.touching(grid, |c| c.is_engine_part()).is_empty())
+            .map(|number| number.value)
+            .sum())
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Result<i32, Error> {
+        let mut touches: HashMap<Point, Vec<i32>> = HashMap::new();
+
+        for number in part_numbers(grid) {
+            for gear in number.touching(grid, |c| c.is_gear_part()) {
+                touches.entry(gear).or_default().push(number.value);
+            }
+        }
+
+        Ok(touches
+            .values()
+            .filter(|v| v.len() == 2)
+            .map(|v| v.iter().product::<i32>())
+            .sum())
+    }
+}
+
+/// A run of digits on one row of the schematic.
+struct PartNumber {
+    value: i32,
+    cells: Vec<Point>,
+}
+
+impl PartNumber {
+    /// Every distinct cell around the number that satisfies `checker`.
+    fn touching(&self, grid: &Grid<char>, checker: impl Fn(char) -> bool) -> HashSet<Point> {
+        self.cells
+            .iter()
+            .flat_map(|&cell| grid.neighbours8(cell))
+            .filter(|&point| checker(grid[point]))
+            .collect()
+    }
+}
+
+fn part_numbers(grid: &Grid<char>) -> impl Iterator<Item = PartNumber> + '_ {
+    (0..grid.height()).flat_map(move |y| {
+        let mut numbers = vec![];
+        let mut current: Option<PartNumber> = None;
+
+        for (x, c) in grid.row(y).iter().enumerate() {
+            match (c.to_digit(10), current.as_mut()) {
+                (Some(digit), Some(number)) => {
+                    number.value = number.value * 10 + digit as i32;
+                    number.cells.push(Point::new(x, y));
+                }
+                (Some(digit), None) => {
+                    current = Some(PartNumber {
+                        value: digit as i32,
+                        cells: vec![Point::new(x, y)],
+                    });
+                }
+                (None, _) => numbers.extend(current.take()),
+            }
+        }
+
+        numbers.extend(current);
+        numbers
+    })
+}
+
+pub fn solution_part_1(input: &str) -> Result<i32, Error> {
+    Day3::part1(&Day3::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<i32, Error> {
+    Day3::part2(&Day3::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(4361));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(467835));
+    }
+}
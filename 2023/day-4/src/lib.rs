@@ -0,0 +1,128 @@
+use aoc_core::{Error, ParseError, Solution, Source};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let source = Source::new(input);
+
+        source
+            .lines()
+            .map(|line| {
+                let (_, numbers) = source.split_once(line, ":")?;
+                let (winning, own) = source.split_once(numbers, "|")?;
+
+                Ok(Card {
+                    winning: source.numbers(winning)?,
+                    numbers: source.numbers(own)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> Result<u32, Error> {
+        Ok(cards
+            .iter()
+            .map(|card| match card.matches() {
+                0 => 0,
+                matches => u32::pow(2, matches as u32 - 1),
+            })
+            .sum::<u32>())
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> Result<u32, Error> {
+        let mut cards_copies = vec![0; cards.len()];
+
+        for (idx, card) in cards.iter().enumerate() {
+            let won = idx + 1..idx + 1 + card.matches();
+
+            if won.end > cards.len() {
+                return Err(Error::NoSolution(format!(
+                    "card {} wins copies of cards past the end of the table",
+                    idx + 1
+                )));
+            }
+
+            let copies = cards_copies[idx] + 1;
+
+            for won_copies in &mut cards_copies[won] {
+                *won_copies += copies;
+            }
+
+            cards_copies[idx] += 1;
+        }
+
+        Ok(cards_copies.iter().sum())
+    }
+}
+
+pub struct Card {
+    winning: Vec<u32>,
+    numbers: Vec<u32>,
+}
+
+impl Card {
+    fn matches(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|x| self.winning.contains(x))
+            .count()
+    }
+}
+
+pub fn solution_part_1(input: &str) -> Result<u32, Error> {
+    Day4::part1(&Day4::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<u32, Error> {
+    Day4::part2(&Day4::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(13));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(30));
+    }
+
+    #[test]
+    fn winning_cards_past_the_end_have_no_solution() {
+        assert_eq!(solution_part_1("Card 1: 1 2 | 1 2"), Ok(2));
+        assert!(matches!(
+            solution_part_2("Card 1: 1 2 | 1 2"),
+            Err(Error::NoSolution(_))
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::RangeMap;
+use aoc_core::{ParseError, Source};
+use std::ops::Range;
+
+/// One `<from>-to-<to> map:` block.
+#[derive(Debug)]
+struct Stage {
+    from: String,
+    to: String,
+    map: RangeMap,
+}
+
+/// The seeds and every category map, in the order the input declares them.
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    /// The seeds read as `start length` pairs, or why they cannot be. Only
+    /// part 2 reads them this way, so only part 2 fails on it.
+    seed_ranges: Result<Vec<Range<u64>>, ParseError>,
+    stages: Vec<Stage>,
+}
+
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+        let source = Source::new(input);
+        let mut blocks = input.split("\n\n");
+
+        let line = blocks
+            .next()
+            .ok_or_else(|| source.error(source.end(), "a `seeds:` line"))?;
+        let text = source.strip_prefix(line, "seeds: ")?;
+        let seeds = source.numbers(text)?;
+        let seed_ranges = parse_seed_ranges(&source, text, &seeds);
+
+        let stages = blocks
+            .map(|block| parse_stage(&source, block))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Almanac {
+            seeds,
+            seed_ranges,
+            stages,
+        })
+    }
+
+    /// The seeds as ranges, failing if they do not pair up or a range ends
+    /// past `u64::MAX`.
+    pub fn seed_ranges(&self) -> Result<&[Range<u64>], ParseError> {
+        self.seed_ranges.as_deref().map_err(Clone::clone)
+    }
+
+    /// Follows `value` from category `from` to category `to`, or `None` if no
+    /// chain of maps connects them.
+    pub fn resolve(&self, from: &str, to: &str, value: u64) -> Option<u64> {
+        Some(
+            self.route(from, to)?
+                .into_iter()
+                .fold(value, |value, map| map.get(value)),
+        )
+    }
+
+    /// Every map from `from` to `to` composed into one.
+    pub fn map_between(&self, from: &str, to: &str) -> Option<RangeMap> {
+        Some(
+            self.route(from, to)?
+                .into_iter()
+                .fold(RangeMap::default(), |composed, map| composed.compose(map)),
+        )
+    }
+
+    fn route(&self, from: &str, to: &str) -> Option<Vec<&RangeMap>> {
+        let mut route = vec![];
+        let mut category = from;
+
+        while category != to {
+            // Each stage is used at most once, so a cycle cannot loop forever.
+            if route.len() == self.stages.len() {
+                return None;
+            }
+
+            let stage = self.stages.iter().find(|stage| stage.from == category)?;
+            route.push(&stage.map);
+            category = &stage.to;
+        }
+
+        Some(route)
+    }
+}
+
+fn parse_seed_ranges(
+    source: &Source,
+    text: &str,
+    seeds: &[u64],
+) -> Result<Vec<Range<u64>>, ParseError> {
+    let tokens = text.split_whitespace().collect::<Vec<_>>();
+
+    if !seeds.len().is_multiple_of(2) {
+        let end = &text[text.trim_end().len()..];
+        return Err(source.error(end, "a range length after the last seed"));
+    }
+
+    seeds
+        .chunks_exact(2)
+        .zip(tokens.chunks_exact(2))
+        .map(|(pair, tokens)| {
+            pair[0]
+                .checked_add(pair[1])
+                .map(|end| pair[0]..end)
+                .ok_or_else(|| source.error(tokens[1], "a range that ends within 64 bits"))
+        })
+        .collect()
+}
+
+fn parse_stage(source: &Source, block: &str) -> Result<Stage, ParseError> {
+    let mut lines = block.lines();
+    let header = lines
+        .next()
+        .ok_or_else(|| source.error(block, "a `<from>-to-<to> map:` header"))?;
+
+    let (from, to) = header
+        .strip_suffix(" map:")
+        .and_then(|categories| categories.split_once("-to-"))
+        .ok_or_else(|| source.error(header, "a `<from>-to-<to> map:` header"))?;
+
+    let rules = lines
+        .map(|line| match source.numbers::<u64>(line)?[..] {
+            [destination, start, length]
+                if start.checked_add(length).is_none()
+                    || destination.checked_add(length).is_none() =>
+            {
+                let length_token = line.split_whitespace().last().unwrap_or(line);
+                Err(source.error(length_token, "a range that ends within 64 bits"))
+            }
+            [destination, start, length] => Ok((destination, start, length)),
+            _ => Err(source.error(line, "three numbers")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Stage {
+        from: from.to_string(),
+        to: to.to_string(),
+        map: RangeMap::new(rules),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHORT: &str = "seeds: 1 7\n\nseed-to-soil map:\n10 0 5\n\nsoil-to-water map:\n0 10 2";
+
+    #[test]
+    fn resolves_along_any_number_of_stages() {
+        let almanac = Almanac::parse(SHORT).unwrap();
+
+        assert_eq!(almanac.seeds, [1, 7]);
+        assert_eq!(almanac.resolve("seed", "soil", 1), Some(11));
+        assert_eq!(almanac.resolve("seed", "water", 1), Some(1));
+        assert_eq!(almanac.resolve("soil", "water", 10), Some(0));
+        assert_eq!(almanac.resolve("seed", "seed", 3), Some(3));
+        assert_eq!(almanac.resolve("water", "seed", 1), None);
+        assert_eq!(almanac.resolve("seed", "location", 1), None);
+    }
+
+    #[test]
+    fn rejects_map_lines_past_u64_max() {
+        for line in ["0 18446744073709551615 2", "18446744073709551615 0 10"] {
+            let input = format!("seeds: 1\n\nseed-to-soil map:\n{line}");
+            let error = Almanac::parse(&input).unwrap_err();
+
+            assert_eq!(error.line, 4);
+            assert_eq!(error.expected, "a range that ends within 64 bits");
+        }
+
+        assert!(Almanac::parse("seeds: 1\n\nseed-to-soil map:\n0 18446744073709551614 1").is_ok());
+    }
+
+    #[test]
+    fn rejects_malformed_headers() {
+        let error = Almanac::parse("seeds: 1\n\nseed to soil:\n1 2 3").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected a `<from>-to-<to> map:` header, found `seed to soil:`"
+        );
+    }
+}
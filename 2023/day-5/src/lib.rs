@@ -0,0 +1,183 @@
+pub use almanac::Almanac;
+use aoc_core::{Error, ParseError, Solution};
+pub use range_map::RangeMap;
+
+pub mod almanac;
+pub mod range_map;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Almanac::parse(input)
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> Result<u64, Error> {
+        let seed_to_location = seed_to_location(almanac)?;
+
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| seed_to_location.get(seed))
+            .min()
+            .ok_or_else(|| Error::NoSolution("the almanac lists no seeds".to_string()))
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> Result<u64, Error> {
+        let seed_to_location = seed_to_location(almanac)?;
+
+        almanac
+            .seed_ranges()?
+            .iter()
+            .flat_map(|seeds| seed_to_location.map_range(seeds.clone()))
+            .map(|locations| locations.start)
+            .min()
+            .ok_or_else(|| Error::NoSolution("the almanac lists no seeds".to_string()))
+    }
+}
+
+fn seed_to_location(almanac: &Almanac) -> Result<RangeMap, Error> {
+    almanac.map_between("seed", "location").ok_or_else(|| {
+        Error::NoSolution("no chain of maps leads from seed to location".to_string())
+    })
+}
+
+pub fn solution_part_1(input: &str) -> Result<u64, Error> {
+    Day5::part1(&Day5::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<u64, Error> {
+    Day5::part2(&Day5::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"#;
+
+    #[test]
+    fn part1() {
+        assert_eq!(solution_part_1(EXAMPLE), Ok(35));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solution_part_2(EXAMPLE), Ok(46));
+    }
+
+    #[test]
+    fn malformed_map_lines_are_rejected() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98\n";
+
+        assert_eq!(
+            solution_part_1(input).unwrap_err().to_string(),
+            "invalid input at line 4, column 1: expected three numbers, found `50 98`"
+        );
+    }
+
+    #[test]
+    fn seeds_that_do_not_pair_up_are_rejected_in_part2() {
+        let input = EXAMPLE.replacen("79 14 55 13", "79 14 55", 1);
+
+        assert_eq!(solution_part_1(&input), Ok(43));
+        assert_eq!(
+            solution_part_2(&input).unwrap_err().to_string(),
+            "invalid input at line 1, column 16: expected a range length after the last seed, \
+             found end of line"
+        );
+    }
+
+    #[test]
+    fn seed_ranges_past_u64_max_are_rejected() {
+        let input = EXAMPLE.replacen("55 13", "55 18446744073709551615", 1);
+
+        assert_eq!(
+            solution_part_2(&input).unwrap_err().to_string(),
+            "invalid input at line 1, column 17: expected a range that ends within 64 bits, \
+             found `18446744073709551615`"
+        );
+    }
+
+    #[test]
+    fn map_lines_past_u64_max_are_rejected() {
+        let input = "seeds: 1 2\n\nseed-to-location map:\n18446744073709551615 0 10";
+
+        for solution in [solution_part_1, solution_part_2] {
+            assert_eq!(
+                solution(input).unwrap_err().to_string(),
+                "invalid input at line 4, column 24: expected a range that ends within 64 bits, \
+                 found `10`"
+            );
+        }
+    }
+
+    #[test]
+    fn part2_agrees_with_brute_force() {
+        let almanac = Almanac::parse(EXAMPLE).unwrap();
+        let categories = [
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location",
+        ];
+
+        let brute_force = almanac
+            .seed_ranges()
+            .unwrap()
+            .iter()
+            .flat_map(|seeds| seeds.clone())
+            .map(|seed| {
+                categories.windows(2).fold(seed, |value, stage| {
+                    almanac.resolve(stage[0], stage[1], value).unwrap()
+                })
+            })
+            .min()
+            .unwrap();
+
+        assert_eq!(solution_part_2(EXAMPLE), Ok(brute_force));
+    }
+}
@@ -0,0 +1,147 @@
+use std::ops::Range;
+
+/// One almanac line: `source` moves to start at `destination`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    source: Range<u64>,
+    destination: u64,
+}
+
+/// A piecewise map of `u64`s. Values covered by a rule move along with it,
+/// everything else maps to itself. Rules never overlap.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    rules: Vec<Rule>,
+}
+
+impl RangeMap {
+    /// Builds a map from `(destination, source, length)` triples, the order
+    /// the almanac lists them in. Both ranges must end within `u64`, which
+    /// the almanac checks while parsing.
+    pub fn new(rules: impl IntoIterator<Item = (u64, u64, u64)>) -> RangeMap {
+        let mut rules = rules
+            .into_iter()
+            .filter(|&(_, _, length)| length > 0)
+            .map(|(destination, source, length)| Rule {
+                source: source..source + length,
+                destination,
+            })
+            .collect::<Vec<_>>();
+
+        rules.sort_by_key(|rule| rule.source.start);
+
+        RangeMap { rules }
+    }
+
+    pub fn get(&self, value: u64) -> u64 {
+        let index = self
+            .rules
+            .partition_point(|rule| rule.source.start <= value);
+
+        match index.checked_sub(1).map(|index| &self.rules[index]) {
+            Some(rule) if rule.source.contains(&value) => {
+                rule.destination + (value - rule.source.start)
+            }
+            _ => value,
+        }
+    }
+
+    /// The images of `range`, one per rule (or gap between rules) it crosses.
+    pub fn map_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        self.split(range)
+            .into_iter()
+            .map(|(source, destination)| destination..destination + (source.end - source.start))
+            .collect()
+    }
+
+    /// The map that applies `self` and then `next`.
+    pub fn compose(&self, next: &RangeMap) -> RangeMap {
+        let rules = self
+            .split(0..u64::MAX)
+            .into_iter()
+            .flat_map(|(source, destination)| {
+                let image = destination..destination + (source.end - source.start);
+
+                next.split(image)
+                    .into_iter()
+                    .map(move |(part, target)| Rule {
+                        source: source.start + (part.start - destination)
+                            ..source.start + (part.end - destination),
+                        destination: target,
+                    })
+            })
+            .filter(|rule| rule.source.start != rule.destination)
+            .collect();
+
+        RangeMap { rules }
+    }
+
+    /// Cuts `range` at rule boundaries into `(source, destination start)`
+    /// pieces, in order and without gaps.
+    fn split(&self, range: Range<u64>) -> Vec<(Range<u64>, u64)> {
+        let mut pieces = vec![];
+        let mut start = range.start;
+
+        for rule in &self.rules {
+            if rule.source.end <= start {
+                continue;
+            }
+
+            if rule.source.start >= range.end {
+                break;
+            }
+
+            if start < rule.source.start {
+                pieces.push((start..rule.source.start, start));
+                start = rule.source.start;
+            }
+
+            let end = rule.source.end.min(range.end);
+            pieces.push((start..end, rule.destination + (start - rule.source.start)));
+            start = end;
+        }
+
+        if start < range.end {
+            pieces.push((start..range.end, start));
+        }
+
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seed_to_soil() -> RangeMap {
+        RangeMap::new([(50, 98, 2), (52, 50, 48)])
+    }
+
+    #[test]
+    fn maps_values_and_ranges() {
+        let map = seed_to_soil();
+
+        assert_eq!(
+            [79, 14, 55, 13, 98, 99].map(|seed| map.get(seed)),
+            [81, 14, 57, 13, 50, 51]
+        );
+        assert_eq!(map.map_range(45..100), [45..50, 52..100, 50..52]);
+        assert_eq!(map.map_range(10..20), vec![(10..20)]);
+    }
+
+    #[test]
+    fn composes_maps() {
+        let first = seed_to_soil();
+        let second = RangeMap::new([(0, 15, 37), (37, 52, 2), (39, 0, 15)]);
+        let composed = first.compose(&second);
+
+        for value in 0..120 {
+            assert_eq!(
+                composed.get(value),
+                second.get(first.get(value)),
+                "{}",
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use aoc_core::{Error, ParseError, Solution, Source};
+use std::ops::RangeInclusive;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<Race>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let source = Source::new(input);
+
+        let time_line = source.line(0, "a `Time:` line")?;
+        let distance_line = source.line(1, "a `Distance:` line")?;
+
+        let times = source.numbers::<u64>(source.strip_prefix(time_line, "Time:")?)?;
+        let distances = source.numbers::<u64>(source.strip_prefix(distance_line, "Distance:")?)?;
+
+        if times.len() != distances.len() {
+            return Err(source.error(distance_line, format!("{} distances", times.len())));
+        }
+
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Race { time, distance })
+            .collect())
+    }
+
+    fn part1(races: &Self::Input<'_>) -> Result<u64, Error> {
+        Ok(races
+            .iter()
+            .map(|race| count_winning_combinations(race.time, race.distance))
+            .product())
+    }
+
+    fn part2(races: &Self::Input<'_>) -> Result<u64, Error> {
+        // The races are really one race whose numbers were split by spaces.
+        let too_long = || Error::NoSolution("the joined race does not fit in 64 bits".to_string());
+        let time = concat_digits(races.iter().map(|race| race.time)).ok_or_else(too_long)?;
+        let distance_to_beat =
+            concat_digits(races.iter().map(|race| race.distance)).ok_or_else(too_long)?;
+
+        Ok(count_winning_combinations(time, distance_to_beat))
+    }
+}
+
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+/// Glues numbers together digit by digit, or `None` if the result overflows.
+fn concat_digits(mut numbers: impl Iterator<Item = u64>) -> Option<u64> {
+    numbers.try_fold(0_u64, |acc, x| {
+        acc.checked_mul(10_u64.pow(x.to_string().len() as u32))?
+            .checked_add(x)
+    })
+}
+
+/// The hold times that travel further than `distance` in a race lasting
+/// `time`, if any do.
+pub fn winning_holds(time: u64, distance: u64) -> Option<RangeInclusive<u64>> {
+    // hold * (time - hold) > distance, i.e. hold^2 - time * hold + distance < 0
+    let wins = |hold: u64| hold as u128 * (time - hold) as u128 > distance as u128;
+
+    let discriminant = (time as u128 * time as u128).checked_sub(4 * distance as u128)?;
+
+    // Rounding the square root down puts this on the lower root or one above it.
+    let mut low = ((time as u128 - discriminant.isqrt()) / 2) as u64;
+
+    while low <= time / 2 && !wins(low) {
+        low += 1;
+    }
+
+    (low <= time / 2).then(|| low..=time - low)
+}
+
+fn count_winning_combinations(time: u64, distance: u64) -> u64 {
+    winning_holds(time, distance).map_or(0, |holds| holds.end() - holds.start() + 1)
+}
+
+pub fn solution_part_1(input: &str) -> Result<u64, Error> {
+    Day6::part1(&Day6::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<u64, Error> {
+    Day6::part2(&Day6::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn linear_scan(time: u64, distance: u64) -> Vec<u64> {
+        (0..=time)
+            .filter(|hold| hold * (time - hold) > distance)
+            .collect()
+    }
+
+    #[test]
+    fn part1() {
+        let input = r#"
+Time:      7  15   30
+Distance:  9  40  200"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(288));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"
+Time:      7  15   30
+Distance:  9  40  200"#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(71503));
+    }
+
+    #[test]
+    fn exact_bounds_at_the_edges() {
+        // 4 and 6 only tie the record, 5 beats it by one.
+        assert_eq!(winning_holds(10, 24), Some(5..=5));
+        assert_eq!(winning_holds(10, 25), None);
+        assert_eq!(winning_holds(0, 0), None);
+        assert_eq!(winning_holds(2, 0), Some(1..=1));
+        // 1 * (u64::MAX - 1) falls one short, so the products need u128.
+        assert_eq!(winning_holds(u64::MAX, u64::MAX), Some(2..=u64::MAX - 2));
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_the_linear_scan(time in 0_u64..2_000, distance in 0_u64..1_000_000) {
+            let winners = linear_scan(time, distance);
+            let bounds = winners.first().zip(winners.last()).map(|(&first, &last)| first..=last);
+
+            prop_assert_eq!(winning_holds(time, distance), bounds);
+            prop_assert_eq!(count_winning_combinations(time, distance), winners.len() as u64);
+        }
+
+        #[test]
+        fn perfect_squares_tie_the_record(half in 0_u64..100_000) {
+            // Holding for exactly half the race only ties a record of half^2.
+            prop_assert_eq!(winning_holds(2 * half, half * half), None);
+        }
+    }
+}
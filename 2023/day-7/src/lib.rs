@@ -0,0 +1,138 @@
+use aoc_core::{Error, ParseError, Solution, Source};
+use card::*;
+pub use ruleset::{HandType, Ruleset};
+
+pub mod card;
+pub mod ruleset;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, Error> {
+        solution_part_1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<usize, Error> {
+        solution_part_2(input)
+    }
+}
+
+fn parse_games(input: &str, ruleset: &Ruleset) -> Result<Vec<Game>, ParseError> {
+    let source = Source::new(input);
+    let expected = format!("a card ({})", ruleset.describe());
+
+    source
+        .lines()
+        .map(|line| {
+            let (hand, bid) = source.split_once(line, " ")?;
+
+            let cards = source.chars(hand, &expected, |c| ruleset.card(c))?;
+            let hand = ruleset.hand(&cards).ok_or_else(|| {
+                source.error(hand, format!("a hand of {} cards", ruleset.hand_size()))
+            })?;
+
+            Ok(Game {
+                hand,
+                bid: source.number(bid.trim())?,
+            })
+        })
+        .collect()
+}
+
+fn total_winnings(mut games: Vec<Game>) -> usize {
+    // The sort is stable, so equal hands keep the order they were dealt in.
+    games.sort_by_key(|game| game.hand);
+
+    games
+        .iter()
+        .enumerate()
+        .map(|(idx, game)| (idx + 1) * game.bid)
+        .sum()
+}
+
+/// The total winnings of every hand in `input` when played under `ruleset`.
+pub fn winnings(input: &str, ruleset: &Ruleset) -> Result<usize, Error> {
+    Ok(total_winnings(parse_games(input, ruleset)?))
+}
+
+pub fn solution_part_1(input: &str) -> Result<usize, Error> {
+    winnings(input, &Ruleset::standard())
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    winnings(input, &Ruleset::jokers())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#"
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(6440));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(5905));
+    }
+
+    #[test]
+    fn unknown_cards_are_rejected() {
+        let error = parse_games("32T3K 765\nT55X5 684", &Ruleset::standard()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a card (2-9, T, J, Q, K or A), found `X`"
+        );
+    }
+
+    #[test]
+    fn duplicate_hands_keep_their_order() {
+        // Both `32T3K` are one pair and rank 1 and 2 in the order dealt.
+        assert_eq!(solution_part_1("32T3K 765\n32T3K 10\nKK677 1"), Ok(788));
+        assert_eq!(solution_part_1("32T3K 10\n32T3K 765\nKK677 1"), Ok(1543));
+    }
+
+    #[test]
+    fn house_rules_set_the_hand_size() {
+        let six_cards = Ruleset::new("23456789TJQKA", "", 6);
+
+        // Three pairs beat two, and a triple beats both.
+        assert_eq!(
+            winnings("223344 1\n22334K 10\n2223AK 100", &six_cards),
+            Ok(312)
+        );
+        assert_eq!(
+            winnings("22334 1", &six_cards).unwrap_err().to_string(),
+            "invalid input at line 1, column 1: expected a hand of 6 cards, found `22334`"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use crate::card::{Card, Hand};
+
+/// How a game of Camel Cards is played: the cards from weakest to strongest,
+/// which of them are wild, and how many make a hand.
+#[derive(Debug, Clone)]
+pub struct Ruleset {
+    order: Vec<char>,
+    wild: Vec<bool>,
+    hand_size: usize,
+    shapes: Vec<HandType>,
+}
+
+/// The shape of a hand: how many of each kind it holds, largest first.
+/// Comparing shapes lexicographically ranks them the way poker does, so
+/// `[5]` beats `[4, 1]`, which beats `[3, 2]`, and so on.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandType(pub Vec<u8>);
+
+impl Ruleset {
+    /// `order` lists the cards from weakest to strongest. Any card in `wilds`
+    /// stands in for whichever card makes the hand strongest.
+    ///
+    /// # Panics
+    ///
+    /// If there are more than 16 cards, or a hand of `hand_size` of them does
+    /// not fit in a [`Hand`] key.
+    pub fn new(order: &str, wilds: &str, hand_size: usize) -> Ruleset {
+        let order = order.chars().collect::<Vec<_>>();
+        let wild = order.iter().map(|&c| wilds.contains(c)).collect();
+        let mut shapes = partitions(hand_size as u8, hand_size as u8);
+        shapes.sort_unstable();
+
+        let shape_bits = shapes.len().next_power_of_two().trailing_zeros() as usize;
+        assert!(order.len() <= 16, "a card rank must fit in four bits");
+        assert!(
+            4 * hand_size + shape_bits <= 32,
+            "a hand of {hand_size} cards does not fit in a 32-bit key"
+        );
+
+        Ruleset {
+            order,
+            wild,
+            hand_size,
+            shapes: shapes.into_iter().map(HandType).collect(),
+        }
+    }
+
+    /// The rules for part 1.
+    pub fn standard() -> Ruleset {
+        Ruleset::new("23456789TJQKA", "", 5)
+    }
+
+    /// The rules for part 2, where `J` is the weakest card but wild.
+    pub fn jokers() -> Ruleset {
+        Ruleset::new("J23456789TQKA", "J", 5)
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn card(&self, c: char) -> Option<Card> {
+        self.order
+            .iter()
+            .position(|&card| card == c)
+            .map(|rank| Card(rank as u8))
+    }
+
+    /// Every wild card joins the largest group, which is always the best
+    /// upgrade because shapes compare by their largest group first.
+    pub fn hand_type(&self, cards: &[Card]) -> HandType {
+        let mut counts = vec![0_u8; self.order.len()];
+        let mut wilds = 0;
+
+        for card in cards {
+            match self.wild[card.0 as usize] {
+                true => wilds += 1,
+                false => counts[card.0 as usize] += 1,
+            }
+        }
+
+        let mut shape = counts
+            .into_iter()
+            .filter(|&count| count > 0)
+            .collect::<Vec<_>>();
+        shape.sort_unstable_by(|a, b| b.cmp(a));
+
+        match shape.first_mut() {
+            Some(largest) => *largest += wilds,
+            None if wilds > 0 => shape.push(wilds),
+            None => {}
+        }
+
+        HandType(shape)
+    }
+
+    /// The hand's sort key, or `None` if it holds the wrong number of cards.
+    pub fn hand(&self, cards: &[Card]) -> Option<Hand> {
+        if cards.len() != self.hand_size {
+            return None;
+        }
+
+        // Every shape of this many cards is listed in order, so its index
+        // ranks it in as few bits as possible.
+        let shape = self.shapes.binary_search(&self.hand_type(cards)).ok()? as u32;
+
+        Some(Hand(
+            cards
+                .iter()
+                .fold(shape, |key, card| key << 4 | card.0 as u32),
+        ))
+    }
+
+    /// The cards in order, with runs of three or more digits shortened, e.g.
+    /// `2-9, T, J, Q, K or A`.
+    pub fn describe(&self) -> String {
+        let mut parts = vec![];
+        let mut cards = self.order.as_slice();
+
+        while let Some(&first) = cards.first() {
+            let run = 1 + cards
+                .windows(2)
+                .take_while(|pair| pair[0].is_ascii_digit() && pair[1] as u32 == pair[0] as u32 + 1)
+                .count();
+
+            let taken = match run >= 3 {
+                true => {
+                    parts.push(format!("{first}-{}", cards[run - 1]));
+                    run
+                }
+                false => {
+                    parts.push(first.to_string());
+                    1
+                }
+            };
+
+            cards = &cards[taken..];
+        }
+
+        match parts.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => parts.concat(),
+        }
+    }
+}
+
+/// Every way to write `n` as a sum of parts no larger than `largest`, each
+/// listed largest part first.
+fn partitions(n: u8, largest: u8) -> Vec<Vec<u8>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+
+    (1..=n.min(largest))
+        .flat_map(|first| {
+            partitions(n - first, first).into_iter().map(move |rest| {
+                let mut shape = vec![first];
+                shape.extend(rest);
+                shape
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand_type(ruleset: &Ruleset, hand: &str) -> HandType {
+        let cards = hand
+            .chars()
+            .map(|c| ruleset.card(c).unwrap())
+            .collect::<Vec<_>>();
+        ruleset.hand_type(&cards)
+    }
+
+    #[test]
+    fn wilds_join_the_largest_group() {
+        let jokers = Ruleset::jokers();
+
+        assert_eq!(
+            hand_type(&Ruleset::standard(), "KTJJT"),
+            HandType(vec![2, 2, 1])
+        );
+        assert_eq!(hand_type(&jokers, "KTJJT"), HandType(vec![4, 1]));
+        assert_eq!(hand_type(&jokers, "2345J"), HandType(vec![2, 1, 1, 1]));
+        assert_eq!(hand_type(&jokers, "22J33"), HandType(vec![3, 2]));
+        assert_eq!(hand_type(&jokers, "JJJJJ"), HandType(vec![5]));
+    }
+
+    #[test]
+    fn house_rules() {
+        let six_cards = Ruleset::new("23456789TJQKA", "", 6);
+        assert_eq!(hand_type(&six_cards, "223344"), HandType(vec![2, 2, 2]));
+        assert!(hand_type(&six_cards, "222234") > hand_type(&six_cards, "222333"));
+        assert!(hand_type(&six_cards, "222333") > hand_type(&six_cards, "223344"));
+
+        let two_wilds = Ruleset::new("J23456789TQKA", "J2", 5);
+        assert_eq!(hand_type(&two_wilds, "2J9KK"), HandType(vec![4, 1]));
+        assert_eq!(hand_type(&two_wilds, "22JJ2"), HandType(vec![5]));
+    }
+
+    #[test]
+    fn hand_keys_sort_by_type_then_card_by_card() {
+        let key = |ruleset: &Ruleset, hand: &str| {
+            let cards = hand
+                .chars()
+                .map(|c| ruleset.card(c).unwrap())
+                .collect::<Vec<_>>();
+            ruleset.hand(&cards).unwrap()
+        };
+        let (standard, jokers) = (Ruleset::standard(), Ruleset::jokers());
+
+        assert!(key(&standard, "33332") > key(&standard, "2AAAA"));
+        assert!(key(&standard, "2AAAA") > key(&standard, "AAAKK"));
+        assert!(key(&standard, "KK677") > key(&standard, "KTJJT"));
+        assert!(key(&jokers, "KTJJT") > key(&jokers, "KK677"));
+        assert!(key(&jokers, "QQQQ2") > key(&jokers, "JKKK2"));
+        assert_eq!(key(&standard, "T55J5"), key(&standard, "T55J5"));
+        assert_eq!(standard.hand(&[Card(0); 4]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in a 32-bit key")]
+    fn rejects_hands_too_large_for_a_key() {
+        Ruleset::new("23456789TJQKA", "", 8);
+    }
+
+    #[test]
+    fn describes_the_cards() {
+        assert_eq!(Ruleset::standard().describe(), "2-9, T, J, Q, K or A");
+        assert_eq!(Ruleset::jokers().describe(), "J, 2-9, T, Q, K or A");
+        assert_eq!(Ruleset::new("2357", "", 5).describe(), "2, 3, 5 or 7");
+    }
+}
@@ -0,0 +1,344 @@
+use aoc_core::math::{crt, lcm_all, Overflow};
+use aoc_core::{Error, ParseError, Solution, Source};
+use itertools::Itertools;
+pub use network::{Instruction, Network};
+
+pub mod network;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Network;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let source = Source::new(input);
+        let (instructions, nodes) = source.split_once(input, "\n\n")?;
+
+        let instructions = source.chars(instructions, "`L` or `R`", Instruction::from_char)?;
+
+        let lines = nodes
+            .lines()
+            .map(|line| {
+                let (name, branches) = source.split_once(line, " = ")?;
+                let branches = source.strip_prefix(branches, "(")?;
+                let (left, right) = source.split_once(branches, ", ")?;
+                let right = right
+                    .strip_suffix(")")
+                    .ok_or_else(|| source.error(right, "`)`"))?;
+
+                Ok((name, left, right))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        Network::new(instructions, &lines)
+            .map_err(|unknown| source.error(unknown, "the name of a listed node"))
+    }
+
+    fn part1(network: &Self::Input<'_>) -> Result<String, Error> {
+        let start = network
+            .id("AAA")
+            .ok_or_else(|| Error::NoSolution("there is no `AAA` node".to_string()))?;
+        let end = network.id("ZZZ");
+        let mut node = start;
+
+        // Passes start from one of `len` nodes, so after that many the walk repeats.
+        for pass in 0..network.len() {
+            if let Some(&(step, _)) = network
+                .z_stops(node)
+                .iter()
+                .find(|&&(_, stop)| Some(stop) == end)
+            {
+                return Ok((pass * network.instruction_count() + step).to_string());
+            }
+
+            node = network.after_pass(node);
+        }
+
+        Err(Error::NoSolution(
+            "`ZZZ` cannot be reached from `AAA`".to_string(),
+        ))
+    }
+
+    fn part2(network: &Self::Input<'_>) -> Result<usize, Error> {
+        let ghosts = network
+            .ids()
+            .filter(|&id| network.name(id).ends_with('A'))
+            .map(|start| Ghost::analyse(network, start))
+            .collect_vec();
+
+        if ghosts.is_empty() {
+            return Err(Error::NoSolution("there are no `A` nodes".to_string()));
+        }
+
+        match first_common_arrival(&ghosts) {
+            Ok(Some(step)) => Ok(step),
+            Ok(None) => Err(Error::NoSolution(
+                "the ghosts never stand on `Z` nodes at the same time".to_string(),
+            )),
+            Err(Overflow) => Err(Error::NoSolution(
+                "the ghosts' first common arrival does not fit in 64 bits".to_string(),
+            )),
+        }
+    }
+}
+
+/// How one ghost's walk repeats. After `tail` steps it goes round the same
+/// (node, instruction) states every `cycle` steps. `tail_hits` and
+/// `cycle_hits` are the steps in each part that land on a `Z` node.
+#[derive(Debug, PartialEq)]
+struct Ghost {
+    tail: usize,
+    cycle: usize,
+    tail_hits: Vec<usize>,
+    cycle_hits: Vec<usize>,
+}
+
+impl Ghost {
+    /// Walks a whole pass at a time until a pass starts on a node seen before.
+    fn analyse(network: &Network, start: u32) -> Ghost {
+        let length = network.instruction_count();
+        let mut seen = vec![None; network.len()];
+        let mut hits = vec![];
+        let mut node = start;
+
+        if network.name(start).ends_with('Z') {
+            hits.push(0);
+        }
+
+        for pass in 0.. {
+            if let Some(first) = seen[node as usize] {
+                let (tail, end) = (first * length, pass * length);
+                let (tail_hits, cycle_hits) = hits
+                    .into_iter()
+                    .filter(|&hit| hit < end)
+                    .partition(|&hit| hit < tail);
+
+                return Ghost {
+                    tail,
+                    cycle: end - tail,
+                    tail_hits,
+                    cycle_hits,
+                };
+            }
+
+            seen[node as usize] = Some(pass);
+            hits.extend(
+                network
+                    .z_stops(node)
+                    .iter()
+                    .map(|&(step, _)| pass * length + step),
+            );
+            node = network.after_pass(node);
+        }
+
+        unreachable!("a walk over finitely many states repeats")
+    }
+
+    fn arrives_at(&self, step: usize) -> bool {
+        if step < self.tail {
+            self.tail_hits.contains(&step)
+        } else {
+            let offset = self.tail + (step - self.tail) % self.cycle;
+            self.cycle_hits.contains(&offset)
+        }
+    }
+}
+
+/// The first step at which every ghost is on a `Z` node, `None` if there is
+/// no such step, or [`Overflow`] if it is too large to work out.
+fn first_common_arrival(ghosts: &[Ghost]) -> Result<Option<usize>, Overflow> {
+    // The official inputs are built so that every ghost hits `Z` exactly at the
+    // end of each cycle, which makes the answer the lcm of the cycle lengths.
+    if ghosts
+        .iter()
+        .all(|ghost| ghost.tail_hits.is_empty() && ghost.cycle_hits == [ghost.cycle])
+    {
+        return lcm_all(&ghosts.iter().map(|ghost| ghost.cycle).collect_vec()).map(Some);
+    }
+
+    // Until every ghost is inside its cycle, check each step directly.
+    let settled = ghosts
+        .iter()
+        .map(|ghost| ghost.tail)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    if let Some(step) = (1..settled).find(|&step| ghosts.iter().all(|ghost| ghost.arrives_at(step)))
+    {
+        return Ok(Some(step));
+    }
+
+    // After that every ghost is periodic, so combine each choice of `Z` hit
+    // per ghost with the Chinese remainder theorem.
+    let mut congruences = vec![(0, 1)];
+
+    for ghost in ghosts {
+        let cycle = ghost.cycle as u64;
+
+        congruences = congruences
+            .into_iter()
+            .cartesian_product(&ghost.cycle_hits)
+            .filter_map(|((residue, modulus), &hit)| {
+                crt(residue, modulus, hit as u64 % cycle, cycle).transpose()
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unique()
+            .collect();
+    }
+
+    let steps = congruences
+        .into_iter()
+        .map(|(residue, modulus)| {
+            let settled = settled as u64;
+
+            match residue < settled {
+                true => (settled - residue)
+                    .div_ceil(modulus)
+                    .checked_mul(modulus)
+                    .and_then(|skipped| skipped.checked_add(residue))
+                    .ok_or(Overflow),
+                false => Ok(residue),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(steps.into_iter().min().map(|step| step as usize))
+}
+
+pub fn solution_part_1(input: &str) -> Result<String, Error> {
+    Day8::part1(&Day8::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<usize, Error> {
+    Day8::part2(&Day8::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#"
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok("2".to_string()));
+    }
+
+    #[test]
+    fn part1_1() {
+        let input = r#"
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok("6".to_string()));
+    }
+
+    #[test]
+    fn part_2() {
+        let input = r#"
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(6));
+    }
+
+    #[test]
+    fn bad_instructions_and_branches_are_rejected() {
+        let error = Day8::parse("LXR\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (1, 2, "`L` or `R`")
+        );
+
+        let error = Day8::parse("L\n\nAAA = (AAA, BBB)").err().unwrap();
+        assert_eq!(
+            (error.line, error.column, error.found.as_str()),
+            (3, 13, "BBB")
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = r#"
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(6));
+    }
+
+    #[test]
+    fn part2_without_the_lcm_shortcut() {
+        // 11A is on a `Z` node at every even step and 22A at 1, 4, 7, ...
+        // so the lcm of their first hits (2 and 1) would be wrong.
+        let offset = "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11B, 11B)\n\
+                      22A = (22Z, 22Z)\n22Z = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)";
+        assert_eq!(solution_part_2(offset), Ok(4));
+
+        // 44A only passes a `Z` node once, before it settles into a loop.
+        let tail = "L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)\n44A = (44Z, 44Z)\n\
+                    44Z = (44B, 44B)\n44B = (44B, 44B)";
+        assert_eq!(solution_part_2(tail), Ok(1));
+
+        // 11A is only on `Z` at even steps and 33A only at odd ones.
+        let never = "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11B, 11B)\n\
+                     33A = (33Z, 33Z)\n33Z = (33B, 33B)\n33B = (33Z, 33Z)";
+        assert!(matches!(solution_part_2(never), Err(Error::NoSolution(_))));
+    }
+
+    #[test]
+    fn arrivals_past_64_bits_are_overflows() {
+        let ghost = |cycle: usize, hit: usize| Ghost {
+            tail: 0,
+            cycle,
+            tail_hits: vec![],
+            cycle_hits: vec![hit],
+        };
+        let (a, b) = (1 << 40, (1 << 40) - 1);
+
+        // Hits at the end of every cycle take the lcm shortcut.
+        assert_eq!(
+            first_common_arrival(&[ghost(a, a), ghost(b, b)]),
+            Err(Overflow)
+        );
+        // Anywhere else they go through the Chinese remainder theorem.
+        assert_eq!(
+            first_common_arrival(&[ghost(a, 1), ghost(b, 2)]),
+            Err(Overflow)
+        );
+        assert_eq!(first_common_arrival(&[ghost(4, 1), ghost(6, 2)]), Ok(None));
+    }
+}
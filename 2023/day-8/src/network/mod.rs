@@ -0,0 +1,211 @@
+use std::{collections::HashMap, fmt::Write};
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Right = b'R',
+    Left = b'L',
+}
+
+impl Instruction {
+    pub fn from_char(c: char) -> Option<Instruction> {
+        match c {
+            'R' => Some(Instruction::Right),
+            'L' => Some(Instruction::Left),
+            _ => None,
+        }
+    }
+
+    /// The index of the branch this instruction takes in a `[left, right]` pair.
+    fn branch(self) -> usize {
+        match self {
+            Instruction::Left => 0,
+            Instruction::Right => 1,
+        }
+    }
+}
+
+/// Hands out dense ids for node names in the order they are first seen.
+#[derive(Debug, Default)]
+struct Interner {
+    ids: HashMap<String, u32>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    fn intern(&mut self, name: &str) -> u32 {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len() as u32;
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+
+        id
+    }
+}
+
+/// The map compiled to dense node ids, with every node's `[left, right]`
+/// branches and where one full pass of the instructions takes it.
+#[derive(Debug)]
+pub struct Network {
+    instructions: Vec<Instruction>,
+    interner: Interner,
+    edges: Vec<[u32; 2]>,
+    passes: Vec<u32>,
+    z_stops: Vec<Vec<(usize, u32)>>,
+}
+
+impl Network {
+    /// Builds the network from `(name, left, right)` nodes, or returns the
+    /// first branch that names no node.
+    pub fn new<'a>(
+        instructions: Vec<Instruction>,
+        nodes: &[(&'a str, &'a str, &'a str)],
+    ) -> Result<Network, &'a str> {
+        let mut interner = Interner::default();
+
+        for (name, _, _) in nodes {
+            interner.intern(name);
+        }
+
+        let mut edges = vec![[0; 2]; interner.names.len()];
+
+        for (name, left, right) in nodes {
+            let lookup = |branch: &'a str| interner.ids.get(branch).copied().ok_or(branch);
+
+            edges[interner.ids[*name] as usize] = [lookup(left)?, lookup(right)?];
+        }
+
+        let mut network = Network {
+            instructions,
+            interner,
+            edges,
+            passes: vec![],
+            z_stops: vec![],
+        };
+
+        let (passes, z_stops) = (0..network.len() as u32)
+            .map(|node| {
+                let mut stops = vec![];
+                let end = network.instructions.iter().enumerate().fold(
+                    node,
+                    |current, (offset, instruction)| {
+                        let next = network.edges[current as usize][instruction.branch()];
+
+                        if network.name(next).ends_with('Z') {
+                            stops.push((offset + 1, next));
+                        }
+
+                        next
+                    },
+                );
+
+                (end, stops)
+            })
+            .unzip();
+
+        network.passes = passes;
+        network.z_stops = z_stops;
+
+        Ok(network)
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn instruction_count(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn id(&self, name: &str) -> Option<u32> {
+        self.interner.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: u32) -> &str {
+        &self.interner.names[id as usize]
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = u32> {
+        0..self.len() as u32
+    }
+
+    /// Where one full pass of the instructions starting at `node` ends.
+    pub fn after_pass(&self, node: u32) -> u32 {
+        self.passes[node as usize]
+    }
+
+    /// Every `(step, node)` within a pass from `node` that lands on a `Z` node,
+    /// counting steps from 1.
+    pub fn z_stops(&self, node: u32) -> &[(usize, u32)] {
+        &self.z_stops[node as usize]
+    }
+
+    /// The network as a Graphviz digraph, one edge per branch.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph network {\n");
+
+        for node in self.ids() {
+            let [left, right] = self.edges[node as usize];
+
+            for (label, next) in [("L", left), ("R", right)] {
+                let (from, to) = (self.name(node), self.name(next));
+                writeln!(dot, "  \"{from}\" -> \"{to}\" [label=\"{label}\"];").unwrap();
+            }
+        }
+
+        dot.push('}');
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network() -> Network {
+        Network::new(
+            vec![Instruction::Left, Instruction::Left, Instruction::Right],
+            &[
+                ("AAA", "BBB", "BBB"),
+                ("BBB", "AAA", "ZZZ"),
+                ("ZZZ", "ZZZ", "ZZZ"),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn jumps_a_whole_pass_at_once() {
+        let network = network();
+        let [aaa, bbb, zzz] = ["AAA", "BBB", "ZZZ"].map(|name| network.id(name).unwrap());
+
+        assert_eq!(network.after_pass(aaa), bbb);
+        assert_eq!(network.after_pass(bbb), zzz);
+        assert_eq!(network.z_stops(aaa), []);
+        assert_eq!(network.z_stops(bbb), [(3, zzz)]);
+    }
+
+    #[test]
+    fn exports_dot() {
+        let dot = network().to_dot();
+
+        assert!(dot.starts_with("digraph network {\n"));
+        assert!(dot.contains("  \"BBB\" -> \"ZZZ\" [label=\"R\"];\n"));
+        assert_eq!(dot.lines().count(), 8);
+    }
+
+    #[test]
+    fn rejects_unknown_branches() {
+        assert_eq!(
+            Network::new(vec![Instruction::Left], &[("AAA", "AAA", "QQQ")]).unwrap_err(),
+            "QQQ"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_core::{Error, ParseError, Solution, Source};
+use itertools::*;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let source = Source::new(input);
+
+        source
+            .lines()
+            .map(|line| match source.numbers::<i32>(line)? {
+                numbers if numbers.is_empty() => Err(source.error(line, "a number")),
+                numbers => Ok(numbers),
+            })
+            .collect()
+    }
+
+    fn part1(rows: &Self::Input<'_>) -> Result<i32, Error> {
+        fn solve_part_1(vec: &[i32], current_sum: i32) -> i32 {
+            let next_row_values = vec.iter().tuple_windows().map(|(a, b)| b - a).collect_vec();
+
+            if next_row_values.iter().all(|x| x.eq(&0)) {
+                current_sum
+            } else {
+                solve_part_1(
+                    &next_row_values,
+                    next_row_values.last().unwrap() + current_sum,
+                )
+            }
+        }
+
+        Ok(rows
+            .iter()
+            .map(|row| solve_part_1(row, *row.last().unwrap()))
+            .sum())
+    }
+
+    fn part2(rows: &Self::Input<'_>) -> Result<i32, Error> {
+        fn solve_part_2(vec: &[i32]) -> i32 {
+            let next_row_values = vec.iter().tuple_windows().map(|(a, b)| b - a).collect_vec();
+
+            if next_row_values.iter().all(|x| x.eq(&0)) {
+                0
+            } else {
+                next_row_values.first().unwrap() - solve_part_2(&next_row_values)
+            }
+        }
+
+        Ok(rows
+            .iter()
+            .map(|row| row.first().unwrap() - solve_part_2(row))
+            .sum())
+    }
+}
+
+pub fn solution_part_1(input: &str) -> Result<i32, Error> {
+    Day9::part1(&Day9::parse(input)?)
+}
+
+pub fn solution_part_2(input: &str) -> Result<i32, Error> {
+    Day9::part2(&Day9::parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let input = r#"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#
+            .trim();
+
+        assert_eq!(solution_part_1(input), Ok(114));
+    }
+
+    #[test]
+    fn part2() {
+        let input = r#"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#
+            .trim();
+
+        assert_eq!(solution_part_2(input), Ok(2));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{solution::Outcome, Answer, Error};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Timed runs to collect.
+    pub samples: u32,
+    /// Sampling stops early once this much time was spent, so slow parts
+    /// still finish; at least one sample is always taken.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            samples: 20,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty. The p95 uses the
+    /// nearest-rank method so it is always one of the measured values.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let p95_rank = (sorted.len() * 95).div_ceil(100);
+
+        Stats {
+            min: sorted[0],
+            median,
+            p95: sorted[p95_rank - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub answer: Answer,
+    pub samples: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Repeats `run` and summarises its parse and solve times. The answer is
+/// the one from the first timed run; the first error stops the benchmark.
+pub fn measure<F>(config: &BenchConfig, mut run: F) -> Result<BenchReport, Error>
+where
+    F: FnMut() -> Result<Outcome, Error>,
+{
+    let started = Instant::now();
+
+    for _ in 0..config.warmup {
+        if started.elapsed() >= config.budget {
+            break;
+        }
+
+        run()?;
+    }
+
+    let started = Instant::now();
+    let mut outcomes: Vec<Outcome> = vec![];
+
+    while outcomes.len() < config.samples.max(1) as usize
+        && (outcomes.is_empty() || started.elapsed() < config.budget)
+    {
+        outcomes.push(run()?);
+    }
+
+    let parse_times = outcomes.iter().map(|o| o.parse_time).collect::<Vec<_>>();
+    let solve_times = outcomes.iter().map(|o| o.solve_time).collect::<Vec<_>>();
+
+    Ok(BenchReport {
+        answer: outcomes[0].answer.clone(),
+        samples: outcomes.len(),
+        parse: Stats::from_samples(&parse_times),
+        solve: Stats::from_samples(&solve_times),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn summarises_odd_and_even_samples() {
+        assert_eq!(
+            Stats::from_samples(&ms(&[5, 1, 3])),
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                p95: Duration::from_millis(5),
+            }
+        );
+
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.p95, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn p95_ignores_the_slowest_outlier_of_many() {
+        let mut samples = ms(&[10; 39]);
+        samples.push(Duration::from_secs(1));
+
+        assert_eq!(Stats::from_samples(&samples).p95, Duration::from_millis(10));
+    }
+
+    fn outcome(answer: u64) -> Result<Outcome, Error> {
+        Ok(Outcome {
+            answer: Answer::Unsigned(answer),
+            parse_time: Duration::from_nanos(answer),
+            solve_time: Duration::from_nanos(answer * 10),
+        })
+    }
+
+    #[test]
+    fn measure_respects_sample_count_and_budget() {
+        let config = BenchConfig {
+            warmup: 2,
+            samples: 7,
+            budget: Duration::from_secs(60),
+        };
+        let mut calls = 0;
+
+        let report = measure(&config, || {
+            calls += 1;
+            outcome(calls)
+        })
+        .unwrap();
+
+        assert_eq!(report.samples, 7);
+        assert_eq!(calls, 9);
+        assert_eq!(report.answer, Answer::Unsigned(3));
+        assert_eq!(report.solve.min, Duration::from_nanos(30));
+        assert_eq!(report.parse.median, Duration::from_nanos(6));
+
+        let exhausted = BenchConfig {
+            budget: Duration::ZERO,
+            ..config
+        };
+
+        assert_eq!(measure(&exhausted, || outcome(1)).unwrap().samples, 1);
+    }
+}
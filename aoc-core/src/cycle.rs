@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Finds where a sequence of states repeats while holding only two of them,
+/// so the states need not be hashable. [`nth_state`] remembers every state
+/// instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleDetector {
+    /// Floyd's tortoise and hare. Steps up to three times as often as
+    /// [`nth_state`].
+    Floyd,
+    /// Brent's teleporting tortoise. Usually steps less than Floyd.
+    Brent,
+}
+
+/// Where a sequence of states starts repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps taken before the first state that repeats.
+    pub prefix_len: usize,
+    /// Steps between repeats.
+    pub cycle_len: usize,
+}
+
+/// The state after `n` steps, and the cycle that led there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NthState<S> {
+    pub state: S,
+    /// `None` if step `n` came before the states repeated, which is then
+    /// all that was stepped.
+    pub cycle: Option<Cycle>,
+}
+
+/// The state after `n` applications of `step` to `initial`, which only
+/// costs as many steps as it takes to find the cycle or reach `n`. Every
+/// state is kept in a `HashMap`, so each is stepped only once. The states
+/// must repeat eventually or `n` must be reachable, or this never returns.
+pub fn nth_state<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    n: usize,
+) -> NthState<S> {
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+
+    loop {
+        if history.len() == n {
+            return NthState { state, cycle: None };
+        }
+
+        if let Some(&prefix_len) = seen.get(&state) {
+            // Every step before `n` was taken, so `n` lies past the prefix.
+            let cycle_len = history.len() - prefix_len;
+
+            return NthState {
+                state: history.swap_remove(prefix_len + (n - prefix_len) % cycle_len),
+                cycle: Some(Cycle {
+                    prefix_len,
+                    cycle_len,
+                }),
+            };
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), history.len());
+        history.push(state);
+        state = next;
+    }
+}
+
+impl CycleDetector {
+    /// Like [`nth_state`], but with states that are only compared.
+    pub fn nth_state<S: Clone + Eq>(
+        self,
+        initial: S,
+        mut step: impl FnMut(&S) -> S,
+        n: usize,
+    ) -> NthState<S> {
+        if n == 0 {
+            return NthState {
+                state: initial,
+                cycle: None,
+            };
+        }
+
+        match self {
+            CycleDetector::Floyd => floyd(initial, &mut step, n),
+            CycleDetector::Brent => brent(initial, &mut step, n),
+        }
+    }
+}
+
+fn advance<S>(state: S, step: &mut impl FnMut(&S) -> S, times: usize) -> S {
+    (0..times).fold(state, |state, _| step(&state))
+}
+
+/// Steps from the start of a cycle found before step `n` to step `n`.
+fn past_the_cycle<S>(
+    cycle_start: S,
+    step: &mut impl FnMut(&S) -> S,
+    cycle: Cycle,
+    n: usize,
+) -> NthState<S> {
+    NthState {
+        state: advance(cycle_start, step, (n - cycle.prefix_len) % cycle.cycle_len),
+        cycle: Some(cycle),
+    }
+}
+
+fn floyd<S: Clone + Eq>(initial: S, step: &mut impl FnMut(&S) -> S, n: usize) -> NthState<S> {
+    // The hare runs twice as fast, so they meet somewhere inside the cycle.
+    // It visits every step on the way, so it passes `n` if that comes first.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    let mut hare_steps = 0;
+
+    loop {
+        tortoise = step(&tortoise);
+
+        for _ in 0..2 {
+            hare = step(&hare);
+            hare_steps += 1;
+
+            if hare_steps == n {
+                return NthState {
+                    state: hare,
+                    cycle: None,
+                };
+            }
+        }
+
+        if tortoise == hare {
+            break;
+        }
+    }
+
+    // The hare is now a whole number of cycles ahead, so restarting the
+    // tortoise from the beginning brings both to the cycle's start together.
+    let mut tortoise = initial;
+    let mut prefix_len = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    let mut cycle_len = 1;
+    let mut hare = step(&tortoise);
+
+    while tortoise != hare {
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    let cycle = Cycle {
+        prefix_len,
+        cycle_len,
+    };
+
+    past_the_cycle(tortoise, step, cycle, n)
+}
+
+fn brent<S: Clone + Eq>(initial: S, step: &mut impl FnMut(&S) -> S, n: usize) -> NthState<S> {
+    // The tortoise jumps to the hare at every power of two, so once the power
+    // exceeds the cycle length the hare laps it within one round.
+    let mut power = 1;
+    let mut cycle_len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    let mut hare_steps = 1;
+
+    while tortoise != hare {
+        if hare_steps == n {
+            return NthState {
+                state: hare,
+                cycle: None,
+            };
+        }
+
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+
+        hare = step(&hare);
+        hare_steps += 1;
+        cycle_len += 1;
+    }
+
+    // With the hare a cycle ahead, both meet where the cycle starts.
+    let mut tortoise = initial.clone();
+    let mut hare = advance(initial, step, cycle_len);
+    let mut prefix_len = 0;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    let cycle = Cycle {
+        prefix_len,
+        cycle_len,
+    };
+
+    past_the_cycle(tortoise, step, cycle, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// [`nth_state`] and then every [`CycleDetector`], named for messages.
+    fn every_detector(
+        initial: u32,
+        step: impl Fn(&u32) -> u32,
+        n: usize,
+    ) -> [(&'static str, NthState<u32>); 3] {
+        [
+            ("HashMap", nth_state(initial, &step, n)),
+            ("Floyd", CycleDetector::Floyd.nth_state(initial, &step, n)),
+            ("Brent", CycleDetector::Brent.nth_state(initial, &step, n)),
+        ]
+    }
+
+    #[test]
+    fn every_detector_finds_the_same_cycle() {
+        // 0, 1, 2, 3, 4, 5 and then 2, 3, 4, 5 over again.
+        let step = |&x: &u32| if x == 5 { 2 } else { x + 1 };
+
+        for (detector, nth) in every_detector(0, step, 1_000_000_001) {
+            assert_eq!(
+                nth.cycle,
+                Some(Cycle {
+                    prefix_len: 2,
+                    cycle_len: 4
+                }),
+                "{detector}"
+            );
+            // 999_999_999 steps into the cycle is 3 past its start at 2.
+            assert_eq!(nth.state, 5, "{detector}");
+        }
+    }
+
+    #[test]
+    fn matches_stepping_one_at_a_time() {
+        let step = |x: &u32| (x * x + 1) % 255;
+
+        for n in 0..300 {
+            let expected = (0..n).fold(3, |x, _| step(&x));
+
+            for (detector, nth) in every_detector(3, step, n) {
+                assert_eq!(nth.state, expected, "{detector}, n = {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_points_are_cycles_of_one() {
+        for (detector, nth) in every_detector(7, |&x| x, 10) {
+            assert_eq!(
+                nth,
+                NthState {
+                    state: 7,
+                    cycle: Some(Cycle {
+                        prefix_len: 0,
+                        cycle_len: 1
+                    })
+                },
+                "{detector}"
+            );
+        }
+    }
+
+    #[test]
+    fn stops_at_n_before_the_cycle() {
+        // A million steps pass before the states repeat.
+        let steps = Cell::new(0);
+        let step = |&x: &u32| {
+            steps.set(steps.get() + 1);
+            (x + 1) % 1_000_000
+        };
+
+        for (detector, nth) in every_detector(0, step, 10) {
+            assert_eq!(
+                nth,
+                NthState {
+                    state: 10,
+                    cycle: None
+                },
+                "{detector}"
+            );
+        }
+
+        // Floyd steps the tortoise as well as the hare.
+        assert!(steps.get() <= 10 + 15 + 10, "{} steps", steps.get());
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+
+/// Where a parser gave up on the input and what it wanted to see there.
+/// Lines and columns start at 1, columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// Points at `token`, which should be a slice of `input`. Tokens from
+    /// anywhere else are reported at the start of the input.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= input.len() && input.is_char_boundary(offset))
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: token.lines().next().unwrap_or("").to_string(),
+        }
+    }
+
+    /// The offending line of `input` with the token underlined.
+    pub fn snippet(&self, input: &str) -> String {
+        let text = input.lines().nth(self.line - 1).unwrap_or("");
+        let gutter = self.line.to_string().len();
+
+        format!(
+            "{:gutter$} |\n{} | {}\n{:gutter$} | {}{} expected {}",
+            "",
+            self.line,
+            text,
+            "",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.found.chars().count().max(1)),
+            self.expected,
+            gutter = gutter
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+
+        match self.found.as_str() {
+            "" => write!(f, "end of line"),
+            found => write!(f, "`{}`", found),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Why a part could not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// The input is well formed but the puzzle has no answer for it.
+    NoSolution(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "invalid input at {}", error),
+            Error::NoSolution(reason) => write!(f, "no solution: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_tokens_by_offset() {
+        let input = "Game 1: 3 blue\nGame 2: 4 gren";
+        let token = &input[input.len() - 4..];
+
+        let error = ParseError::at(input, token, "a colour");
+
+        assert_eq!((error.line, error.column), (2, 11));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 11: expected a colour, found `gren`"
+        );
+        assert_eq!(
+            error.snippet(input),
+            "  |\n2 | Game 2: 4 gren\n  |           ^^^^ expected a colour"
+        );
+    }
+
+    #[test]
+    fn foreign_and_empty_tokens() {
+        let input = "12\n";
+        let foreign = String::from("x");
+
+        assert_eq!(ParseError::at(input, &foreign, "a digit").line, 1);
+        assert_eq!(
+            ParseError::at(input, &input[3..], "a digit").to_string(),
+            "line 2, column 1: expected a digit, found end of line"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+pub mod bench;
+pub mod cycle;
+mod error;
+pub mod geometry;
+pub mod grid;
+pub mod math;
+pub mod parse;
+pub mod solution;
+
+pub use error::{Error, ParseError};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use parse::Source;
+pub use solution::{register, Answer, DynSolution, Part, Solution};
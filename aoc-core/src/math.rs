@@ -0,0 +1,108 @@
+pub fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b > 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+
+    a
+}
+
+/// A result that does not fit in its integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+pub fn lcm(a: usize, b: usize) -> Result<usize, Overflow> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b).ok_or(Overflow)
+}
+
+/// Least common multiple of every number in `nums`, `1` for an empty slice.
+pub fn lcm_all(nums: &[usize]) -> Result<usize, Overflow> {
+    nums.iter().try_fold(1, |acc, &x| lcm(acc, x))
+}
+
+/// Combines `x = a1 (mod m1)` and `x = a2 (mod m2)` into `x = a (mod lcm(m1, m2))`,
+/// `None` if no `x` satisfies both, or [`Overflow`] if the combined modulus
+/// does not fit in a `u64`. The moduli need not be coprime.
+pub fn crt(a1: u64, m1: u64, a2: u64, m2: u64) -> Result<Option<(u64, u64)>, Overflow> {
+    let (g, p, _) = extended_gcd(m1 as i128, m2 as i128);
+    let difference = a2 as i128 - a1 as i128;
+
+    if difference % g != 0 {
+        return Ok(None);
+    }
+
+    let m2_over_g = m2 as i128 / g;
+    let modulus = (m1 as i128 / g)
+        .checked_mul(m2 as i128)
+        .and_then(|modulus| u64::try_from(modulus).ok())
+        .ok_or(Overflow)? as i128;
+
+    // Both factors are reduced below 2^64 first, so their product fits in a
+    // u128 even where it would not in an i128. `m1 * step` is then below the
+    // modulus, which fits in a u64.
+    let step = ((difference / g).rem_euclid(m2_over_g) as u128 * p.rem_euclid(m2_over_g) as u128)
+        .rem_euclid(m2_over_g as u128) as i128;
+    let x = (a1 as i128 + m1 as i128 * step).rem_euclid(modulus);
+
+    Ok(Some((x as u64, modulus as u64)))
+}
+
+/// `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), Ok(12));
+        assert_eq!(lcm_all(&[2, 3, 4]), Ok(12));
+        assert_eq!(lcm_all(&[]), Ok(1));
+        assert_eq!(lcm_all(&[1 << 40, (1 << 40) - 1]), Err(Overflow));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(2, 3, 3, 5), Ok(Some((8, 15))));
+        assert_eq!(crt(1, 4, 3, 6), Ok(Some((9, 12))));
+        assert_eq!(crt(0, 4, 1, 6), Ok(None));
+        assert_eq!(crt(5, 7, 5, 7), Ok(Some((5, 7))));
+    }
+
+    #[test]
+    fn chinese_remainders_with_large_moduli() {
+        let (m1, m2) = (4_294_967_291, 4_294_967_279);
+        let (x, modulus) = crt(u64::MAX, m1, 1 << 40, m2).unwrap().unwrap();
+
+        assert_eq!(modulus, m1 * m2);
+        assert_eq!((x % m1, x % m2), (u64::MAX % m1, (1 << 40) % m2));
+
+        // Coprime and close to 2^64: the Bezout coefficient is about 2^63,
+        // times a difference of about 2^64, and the combined modulus needs
+        // 128 bits.
+        let (m1, m2) = (u64::MAX - 198, u64::MAX - 196);
+        assert_eq!(crt(0, m1, u64::MAX, m2), Err(Overflow));
+        assert_eq!(crt(5, m1, 5, m2), Err(Overflow));
+
+        // One modulus dividing the other, which is above 2^63.
+        let m2 = u64::MAX - 1;
+        let a2 = u64::MAX - 4;
+        assert_eq!(crt(a2 % (m2 / 2), m2 / 2, a2, m2), Ok(Some((a2, m2))));
+        assert_eq!(crt(3, m2 / 2, a2, m2), Ok(None));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{grid::Grid, ParseError};
+use std::{fmt::Display, str::FromStr};
+
+/// The whole puzzle input, so that errors about any slice of it can say
+/// where that slice sits.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(input: &'a str) -> Source<'a> {
+        Source { input }
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.input.lines()
+    }
+
+    /// An empty slice at the very end, for input that stops too early.
+    pub fn end(&self) -> &'a str {
+        &self.input[self.input.len()..]
+    }
+
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.input, token, expected)
+    }
+
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, "a number"))
+    }
+
+    /// Every whitespace separated number in `text`.
+    pub fn numbers<T: FromStr>(&self, text: &str) -> Result<Vec<T>, ParseError> {
+        text.split_whitespace()
+            .map(|token| self.number(token))
+            .collect()
+    }
+
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, format!("`{}`", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        text.strip_prefix(prefix)
+            .ok_or_else(|| self.error(text, format!("`{}`", prefix)))
+    }
+
+    /// The `index`-th (0 based) line, or an error pointing past the last one.
+    pub fn line(&self, index: usize, expected: impl Display) -> Result<&'a str, ParseError> {
+        self.lines()
+            .nth(index)
+            .ok_or_else(|| self.error(self.end(), expected.to_string()))
+    }
+
+    /// Converts every character of `line`, failing on the first one that
+    /// `convert` rejects.
+    pub fn chars<T>(
+        &self,
+        line: &'a str,
+        expected: &str,
+        convert: impl Fn(char) -> Option<T>,
+    ) -> Result<Vec<T>, ParseError> {
+        line.char_indices()
+            .map(|(index, c)| {
+                convert(c).ok_or_else(|| self.error(&line[index..index + c.len_utf8()], expected))
+            })
+            .collect()
+    }
+
+    /// A grid with one cell per character of `text`, a slice of the input.
+    /// All rows must be equally long.
+    pub fn grid<T>(
+        &self,
+        text: &'a str,
+        expected: &str,
+        convert: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+
+        for line in text.lines() {
+            let row = self.chars(line, expected, &convert)?;
+
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(self.error(line, format!("a row of {} cells", first.len())));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Grid::from_rows(rows).expect("rows to have the same length"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_failing_token() {
+        let source = Source::new("seeds: 79 14\nseed-to-soil map:\n50 98 x");
+        let line = source.line(2, "a map line").unwrap();
+
+        assert_eq!(source.numbers::<u64>("79 14"), Ok(vec![79, 14]));
+        assert_eq!(
+            source.numbers::<u64>(line).unwrap_err().to_string(),
+            "line 3, column 7: expected a number, found `x`"
+        );
+        assert_eq!(
+            source.split_once(line, ": ").unwrap_err().to_string(),
+            "line 3, column 1: expected `: `, found `50 98 x`"
+        );
+        assert_eq!(source.line(3, "a map line").unwrap_err().line, 3);
+    }
+
+    #[test]
+    fn reports_grid_errors_against_the_whole_input() {
+        let input = "#.\n..\n\n#.\n#";
+        let source = Source::new(input);
+        let (_, second) = source.split_once(input, "\n\n").unwrap();
+
+        assert_eq!(
+            source.grid(second, "a cell", Some).unwrap_err().to_string(),
+            "line 5, column 1: expected a row of 2 cells, found `#`"
+        );
+    }
+
+    #[test]
+    fn validates_characters() {
+        let source = Source::new("#.#\n.?.");
+        let line = source.line(1, "a row").unwrap();
+
+        assert_eq!(
+            source.chars(line, "`#` or `.`", |c| "#.".contains(c).then_some(c)),
+            Err(ParseError {
+                line: 2,
+                column: 2,
+                expected: "`#` or `.`".to_string(),
+                found: "?".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    bench::{self, BenchConfig, BenchReport},
+    Error, ParseError,
+};
+use std::{fmt, time::Duration, time::Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The value a part produces, whatever integer or text type the day uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::$variant(value as _)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Unsigned: u8, u16, u32, u64, usize);
+answer_from!(Signed: i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A single puzzle. `parse` runs once per part so that parsing and solving
+/// can be timed separately. Days that only parse while solving report their
+/// input errors from the parts as [`Error::Parse`].
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Error>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Error>;
+}
+
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Object safe view of a [`Solution`] so that every day can live in one registry.
+pub trait DynSolution: Send + Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn run(&self, input: &str, part: Part) -> Result<Outcome, Error>;
+
+    fn bench(&self, input: &str, part: Part, config: &BenchConfig) -> Result<BenchReport, Error> {
+        bench::measure(config, || self.run(input, part))
+    }
+}
+
+struct Erased<S>(std::marker::PhantomData<fn() -> S>);
+
+impl<S: Solution> DynSolution for Erased<S> {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, part: Part) -> Result<Outcome, Error> {
+        let start_parse = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse_time = start_parse.elapsed();
+
+        let start_solve = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&parsed)?.into(),
+            Part::Two => S::part2(&parsed)?.into(),
+        };
+        let solve_time = start_solve.elapsed();
+
+        Ok(Outcome {
+            answer,
+            parse_time,
+            solve_time,
+        })
+    }
+}
+
+pub fn register<S: Solution + 'static>() -> Box<dyn DynSolution> {
+    Box::new(Erased::<S>(std::marker::PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Input<'a> = Vec<&'a str>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<usize, Error> {
+            Ok(input
+                .iter()
+                .map(|line| line.parse::<usize>().unwrap())
+                .sum())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<String, Error> {
+            Ok(input.concat())
+        }
+    }
+
+    #[test]
+    fn registered_solution_runs_both_parts() {
+        let solution = register::<Sum>();
+
+        assert_eq!((solution.year(), solution.day()), (2000, 1));
+        assert_eq!(
+            solution.run("1\n2", Part::One).unwrap().answer,
+            Answer::Unsigned(3)
+        );
+        assert_eq!(
+            solution.run("1\n2", Part::Two).unwrap().answer,
+            Answer::Text("12".to_string())
+        );
+    }
+}
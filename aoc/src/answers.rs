@@ -0,0 +1,126 @@
+use aoc_core::Part;
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+};
+
+/// The contents of `answers.toml`: user -> (year, day) -> recorded parts.
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<String, BTreeMap<(u16, u8), DayAnswers>>);
+
+type RawAnswers = HashMap<String, HashMap<String, HashMap<String, DayAnswers>>>;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Recorded>,
+    part2: Option<Recorded>,
+}
+
+/// Answers may be written as TOML integers or strings.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Number(i64),
+    Text(String),
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let raw: RawAnswers = toml::from_str(text).map_err(|error| error.to_string())?;
+
+        let mut answers = HashMap::new();
+
+        for (user, years) in raw {
+            let mut days = BTreeMap::new();
+
+            for (year, year_days) in years {
+                let year = year
+                    .parse::<u16>()
+                    .map_err(|_| format!("invalid year `{}` for `{}`", year, user))?;
+
+                for (day, day_answers) in year_days {
+                    let day = day
+                        .parse::<u8>()
+                        .map_err(|_| format!("invalid day `{}` in {} for `{}`", day, year, user))?;
+
+                    days.insert((year, day), day_answers);
+                }
+            }
+
+            answers.insert(user, days);
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+
+        Answers::parse(&text).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn get(&self, user: &str, year: u16, day: u8, part: Part) -> Option<String> {
+        let day = self.0.get(user)?.get(&(year, day))?;
+
+        let recorded = match part {
+            Part::One => day.part1.as_ref(),
+            Part::Two => day.part2.as_ref(),
+        }?;
+
+        Some(match recorded {
+            Recorded::Number(value) => value.to_string(),
+            Recorded::Text(value) => value.clone(),
+        })
+    }
+
+    /// Every recorded `(year, day, part)` of `user`, in calendar order.
+    pub fn recorded(&self, user: &str) -> Vec<(u16, u8, Part)> {
+        self.0
+            .get(user)
+            .into_iter()
+            .flatten()
+            .flat_map(|(&(year, day), answers)| {
+                [
+                    answers.part1.as_ref().map(|_| (year, day, Part::One)),
+                    answers.part2.as_ref().map(|_| (year, day, Part::Two)),
+                ]
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_numbers_and_text() {
+        let answers = Answers::parse(
+            r#"
+[alice.2023.8]
+part1 = "2"
+part2 = 6
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get("alice", 2023, 8, Part::One), Some("2".into()));
+        assert_eq!(answers.get("alice", 2023, 8, Part::Two), Some("6".into()));
+        assert_eq!(answers.get("alice", 2023, 9, Part::One), None);
+        assert_eq!(answers.get("bob", 2023, 8, Part::One), None);
+        assert_eq!(
+            answers.recorded("alice"),
+            vec![(2023, 8, Part::One), (2023, 8, Part::Two)]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(Answers::parse("[alice.2023.8]\npart3 = 1").is_err());
+        assert!(Answers::parse("[alice.2023.eight]\npart1 = 1").is_err());
+    }
+}
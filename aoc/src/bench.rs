@@ -0,0 +1,355 @@
+use crate::table;
+use aoc_core::{
+    bench::{BenchReport, Stats},
+    Part,
+};
+use std::{fmt, fs, io::Write, path::Path, time::Duration};
+
+const HEADER: &str = "timestamp,year,day,part,input_hash,samples,\
+parse_min_ns,parse_median_ns,parse_p95_ns,solve_min_ns,solve_median_ns,solve_p95_ns";
+
+/// One line of the benchmark history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// FNV-1a of the input, so runs are only compared on the same input.
+    pub input_hash: String,
+    pub samples: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Record {
+    pub fn new(
+        timestamp: u64,
+        year: u16,
+        day: u8,
+        part: Part,
+        input_hash: String,
+        report: &BenchReport,
+    ) -> Record {
+        Record {
+            timestamp,
+            year,
+            day,
+            part,
+            input_hash,
+            samples: report.samples,
+            parse: report.parse,
+            solve: report.solve,
+        }
+    }
+
+    /// Median of a whole run, which is what runs are compared on.
+    pub fn total_median(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+
+    fn to_csv(&self) -> String {
+        let nanos = |stats: &Stats| {
+            [stats.min, stats.median, stats.p95]
+                .map(|duration| duration.as_nanos().to_string())
+                .join(",")
+        };
+
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part,
+            self.input_hash,
+            self.samples,
+            nanos(&self.parse),
+            nanos(&self.solve)
+        )
+    }
+
+    fn from_csv(line: &str) -> Option<Record> {
+        let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+        let [timestamp, year, day, part, input_hash, samples, nanos @ ..] = fields.as_slice()
+        else {
+            return None;
+        };
+
+        let nanos = nanos
+            .iter()
+            .map(|field| field.parse().ok().map(Duration::from_nanos))
+            .collect::<Option<Vec<_>>>()?;
+        let [parse_min, parse_median, parse_p95, solve_min, solve_median, solve_p95] =
+            nanos.as_slice()
+        else {
+            return None;
+        };
+
+        Some(Record {
+            timestamp: timestamp.parse().ok()?,
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            part: match *part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return None,
+            },
+            input_hash: input_hash.to_string(),
+            samples: samples.parse().ok()?,
+            parse: Stats {
+                min: *parse_min,
+                median: *parse_median,
+                p95: *parse_p95,
+            },
+            solve: Stats {
+                min: *solve_min,
+                median: *solve_median,
+                p95: *solve_p95,
+            },
+        })
+    }
+}
+
+/// Every benchmark run recorded so far, oldest first.
+#[derive(Debug, Default)]
+pub struct History(Vec<Record>);
+
+impl History {
+    pub fn parse(text: &str) -> Result<History, String> {
+        text.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with("timestamp"))
+            .map(|(number, line)| {
+                Record::from_csv(line).ok_or_else(|| format!("line {}: invalid record", number + 1))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(History)
+    }
+
+    /// Reads the history at `path`, which may not exist yet.
+    pub fn load(path: &Path) -> Result<History, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                History::parse(&text).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(History::default()),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    /// Appends `records` to the file at `path`, creating it if needed.
+    pub fn append(path: &Path, records: &[Record]) -> Result<(), String> {
+        let describe = |error: std::io::Error| format!("{}: {}", path.display(), error);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(describe)?;
+        }
+
+        let is_new = !path.exists();
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(describe)?;
+
+        if is_new {
+            writeln!(file, "{}", HEADER).map_err(describe)?;
+        }
+
+        for record in records {
+            writeln!(file, "{}", record.to_csv()).map_err(describe)?;
+        }
+
+        Ok(())
+    }
+
+    /// The most recent record of the same part run on the same input.
+    pub fn previous(&self, current: &Record) -> Option<&Record> {
+        self.0.iter().rev().find(|record| {
+            (record.year, record.day, record.part, &record.input_hash)
+                == (current.year, current.day, current.part, &current.input_hash)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    New,
+    Unchanged(f64),
+    Faster(f64),
+    Regression(f64),
+}
+
+impl Change {
+    /// Compares total medians, anything within `threshold` percent counts
+    /// as noise.
+    pub fn between(current: &Record, previous: Option<&Record>, threshold: u32) -> Change {
+        let Some(previous) = previous else {
+            return Change::New;
+        };
+
+        let before = previous.total_median().as_secs_f64();
+        let after = current.total_median().as_secs_f64();
+
+        if before == 0.0 {
+            return Change::Unchanged(0.0);
+        }
+
+        let percent = (after - before) / before * 100.0;
+
+        if percent > threshold as f64 {
+            Change::Regression(percent)
+        } else if percent < -(threshold as f64) {
+            Change::Faster(percent)
+        } else {
+            Change::Unchanged(percent)
+        }
+    }
+}
+
+impl Change {
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Change::New => "new",
+            Change::Unchanged(_) => "unchanged",
+            Change::Faster(_) => "faster",
+            Change::Regression(_) => "regression",
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::New => write!(f, "new"),
+            Change::Unchanged(percent) => write!(f, "{:+.1}%", percent),
+            Change::Faster(percent) => write!(f, "{:+.1}% faster", percent),
+            Change::Regression(percent) => write!(f, "{:+.1}% REGRESSION", percent),
+        }
+    }
+}
+
+fn stats_cell(stats: &Stats) -> String {
+    format!("{:?} / {:?} / {:?}", stats.min, stats.median, stats.p95)
+}
+
+pub fn render_table(results: &[(Record, Change)]) -> String {
+    let rows = results
+        .iter()
+        .map(|(record, change)| {
+            [
+                record.year.to_string(),
+                record.day.to_string(),
+                record.part.to_string(),
+                record.samples.to_string(),
+                stats_cell(&record.parse),
+                stats_cell(&record.solve),
+                change.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    table::render(
+        [
+            "Year",
+            "Day",
+            "Part",
+            "Runs",
+            "Parse min / median / p95",
+            "Solve min / median / p95",
+            "Change",
+        ],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(timestamp: u64, part: Part, solve_median_us: u64) -> Record {
+        let us = Duration::from_micros;
+
+        Record {
+            timestamp,
+            year: 2023,
+            day: 7,
+            part,
+            input_hash: "cbf29ce484222325".to_string(),
+            samples: 20,
+            parse: Stats {
+                min: us(1),
+                median: us(2),
+                p95: us(3),
+            },
+            solve: Stats {
+                min: us(solve_median_us - 1),
+                median: us(solve_median_us),
+                p95: us(solve_median_us + 1),
+            },
+        }
+    }
+
+    #[test]
+    fn records_round_trip_through_csv() {
+        let record = record(1_700_000_000, Part::Two, 98);
+
+        assert_eq!(
+            record.to_csv(),
+            "1700000000,2023,7,2,cbf29ce484222325,20,1000,2000,3000,97000,98000,99000"
+        );
+        assert_eq!(Record::from_csv(&record.to_csv()), Some(record));
+        assert_eq!(
+            Record::from_csv("1,2023,7,3,cbf29ce484222325,20,1,2,3,4,5,6"),
+            None
+        );
+        assert_eq!(
+            Record::from_csv("1,2023,7,1,cbf29ce484222325,20,1,2,3"),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_the_latest_record_of_a_part_on_the_same_input() {
+        let other_input = |mut record: Record| {
+            record.input_hash = "af63bd4c8601b7df".to_string();
+            record
+        };
+        let text = [
+            HEADER.to_string(),
+            record(1, Part::One, 10).to_csv(),
+            record(2, Part::One, 20).to_csv(),
+            record(3, Part::Two, 30).to_csv(),
+            other_input(record(4, Part::One, 40)).to_csv(),
+        ]
+        .join("\n");
+        let history = History::parse(&text).unwrap();
+        let previous = |current: Record| history.previous(&current).map(|record| record.timestamp);
+
+        assert_eq!(previous(record(5, Part::One, 10)), Some(2));
+        assert_eq!(previous(record(5, Part::Two, 10)), Some(3));
+        assert_eq!(previous(other_input(record(5, Part::One, 10))), Some(4));
+        assert_eq!(previous(other_input(record(5, Part::Two, 10))), None);
+        assert!(History::parse("not,a,record").is_err());
+    }
+
+    #[test]
+    fn flags_changes_beyond_the_threshold() {
+        let previous = record(1, Part::One, 98);
+
+        assert_eq!(Change::between(&previous, None, 10), Change::New);
+        assert!(matches!(
+            Change::between(&record(2, Part::One, 108), Some(&previous), 10),
+            Change::Unchanged(_)
+        ));
+        assert!(matches!(
+            Change::between(&record(2, Part::One, 148), Some(&previous), 10),
+            Change::Regression(percent) if (percent - 50.0).abs() < 1e-9
+        ));
+        assert!(matches!(
+            Change::between(&record(2, Part::One, 48), Some(&previous), 10),
+            Change::Faster(_)
+        ));
+    }
+}
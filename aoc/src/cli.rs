@@ -0,0 +1,254 @@
+use crate::{input::InputSource, output::Format};
+use aoc_core::{bench::BenchConfig, Part};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: aoc [run|verify|bench] [--year <YEAR>] [--day <DAY|all>] [--part <1|2|all>]
+           [--input <PATH|-> | --user <NAME>] [--answers <PATH>]
+           [--format <text|json|csv>] [--warmup <N>] [--samples <N>] [--history <PATH>] [--threshold <PERCENT>]
+
+Runs the selected puzzles. Without --day every registered day of the year is
+run. Inputs are read from each day's input.txt unless --user picks
+inputs/<NAME>/<YEAR>/day-<DAY>.txt or --input names a file (`-` for stdin)
+for a single day.
+
+--format json prints one JSON record per part and line, --format csv one CSV
+row, both with year, day, part, answer, duration_ns, input_hash and status.
+Everything else goes to stderr.
+
+`verify` compares every answer with answers.toml and reports PASS, FAIL,
+MISSING (no input or no recorded answer) or ERROR, failing on all but
+unrecorded answers.
+
+`bench` runs each part --warmup times untimed, then up to --samples times
+(stopping early once a part has taken 5s), and reports min, median and p95
+for parsing and solving. Results are appended to target/bench-history.csv
+unless --history names another file, and median changes of more than
+--threshold percent (default 10) against the previous run on the same input
+are flagged. Parts without an input or that fail are reported and left out
+of the history. Any of these or a regression fails the run.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Command {
+    #[default]
+    Run,
+    Verify,
+    Bench,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    pub year: u16,
+    pub day: Option<u8>,
+    pub parts: Vec<Part>,
+    pub input: InputSource,
+    pub answers: Option<PathBuf>,
+    pub format: Format,
+    pub bench: BenchConfig,
+    pub history: Option<PathBuf>,
+    pub threshold: u32,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            command: Command::default(),
+            year: 2023,
+            day: None,
+            parts: Part::ALL.to_vec(),
+            input: InputSource::default(),
+            answers: None,
+            format: Format::default(),
+            bench: BenchConfig::default(),
+            history: None,
+            threshold: 10,
+        }
+    }
+}
+
+impl Args {
+    pub fn parse<I>(args: I) -> Result<Args, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter().peekable();
+
+        if let Some(command) = args.next_if(|arg| !arg.starts_with('-')) {
+            parsed.command = match command.as_str() {
+                "run" => Command::Run,
+                "verify" => Command::Verify,
+                "bench" => Command::Bench,
+                _ => return Err(format!("unknown command `{}`", command)),
+            };
+        }
+
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for `{}`", flag))
+            };
+
+            match flag.as_str() {
+                "--year" => {
+                    let year = value()?;
+                    parsed.year = year
+                        .parse()
+                        .map_err(|_| format!("invalid year `{}`", year))?;
+                }
+                "--day" => {
+                    let day = value()?;
+                    parsed.day = match day.as_str() {
+                        "all" => None,
+                        _ => Some(parse_day(&day)?),
+                    };
+                }
+                "--part" => {
+                    parsed.parts = match value()?.as_str() {
+                        "1" => vec![Part::One],
+                        "2" => vec![Part::Two],
+                        "all" => Part::ALL.to_vec(),
+                        part => return Err(format!("invalid part `{}`", part)),
+                    };
+                }
+                "--input" => {
+                    parsed.input = match value()?.as_str() {
+                        "-" => InputSource::Stdin,
+                        path => InputSource::File(PathBuf::from(path)),
+                    };
+                }
+                "--user" => parsed.input = InputSource::User(value()?),
+                "--answers" => parsed.answers = Some(PathBuf::from(value()?)),
+                "--format" => parsed.format = value()?.parse()?,
+                "--warmup" => parsed.bench.warmup = parse_count(&flag, &value()?)?,
+                "--samples" => {
+                    parsed.bench.samples = match parse_count(&flag, &value()?)? {
+                        0 => return Err("`--samples` must be at least 1".to_string()),
+                        samples => samples,
+                    };
+                }
+                "--history" => parsed.history = Some(PathBuf::from(value()?)),
+                "--threshold" => parsed.threshold = parse_count(&flag, &value()?)?,
+                _ => return Err(format!("unexpected argument `{}`", flag)),
+            }
+        }
+
+        if parsed.input.is_single_day() && parsed.day.is_none() {
+            return Err("`--input` needs a single `--day`".to_string());
+        }
+
+        if parsed.command == Command::Verify && parsed.input.answers_user().is_none() {
+            return Err(
+                "`verify` reads recorded answers, use `--user` instead of `--input`".to_string(),
+            );
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day `{}`, expected 1-25 or `all`", day)),
+    }
+}
+
+fn parse_count(flag: &str, value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{}` for `{}`", value, flag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, String> {
+        Args::parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn defaults_to_every_part_of_every_day() {
+        assert_eq!(parse(""), Ok(Args::default()));
+    }
+
+    #[test]
+    fn selects_a_single_part() {
+        assert_eq!(
+            parse("--year 2023 --day 7 --part 2"),
+            Ok(Args {
+                command: Command::Run,
+                year: 2023,
+                day: Some(7),
+                parts: vec![Part::Two],
+                input: InputSource::Bundled,
+                answers: None,
+                ..Args::default()
+            })
+        );
+    }
+
+    #[test]
+    fn selects_the_input_source() {
+        assert_eq!(
+            parse("--day 1 --input -").map(|args| args.input),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(
+            parse("--day 1 --input in.txt").map(|args| args.input),
+            Ok(InputSource::File(PathBuf::from("in.txt")))
+        );
+        assert_eq!(
+            parse("--user alice").map(|args| args.input),
+            Ok(InputSource::User("alice".to_string()))
+        );
+        assert!(parse("--input in.txt").is_err());
+    }
+
+    #[test]
+    fn selects_the_command() {
+        assert_eq!(
+            parse("run --day 1").map(|args| args.command),
+            Ok(Command::Run)
+        );
+        assert_eq!(
+            parse("verify --user alice").map(|args| args.command),
+            Ok(Command::Verify)
+        );
+        assert!(parse("verify --day 1 --input in.txt").is_err());
+        assert_eq!(parse("bench").map(|args| args.command), Ok(Command::Bench));
+        assert!(parse("profile").is_err());
+    }
+
+    #[test]
+    fn selects_the_output_format() {
+        assert_eq!(parse("").map(|args| args.format), Ok(Format::Text));
+        assert_eq!(
+            parse("verify --format csv").map(|args| args.format),
+            Ok(Format::Csv)
+        );
+        assert!(parse("--format yaml").is_err());
+    }
+
+    #[test]
+    fn reads_bench_options() {
+        let args =
+            parse("bench --day 7 --warmup 1 --samples 50 --threshold 5 --history h.csv").unwrap();
+
+        assert_eq!((args.bench.warmup, args.bench.samples), (1, 50));
+        assert_eq!(args.threshold, 5);
+        assert_eq!(args.history, Some(PathBuf::from("h.csv")));
+        assert!(parse("bench --samples 0").is_err());
+        assert!(parse("bench --warmup -1").is_err());
+    }
+
+    #[test]
+    fn rejects_bad_values() {
+        assert!(parse("--day 26").is_err());
+        assert!(parse("--part 3").is_err());
+        assert!(parse("--day").is_err());
+        assert!(parse("--verbose").is_err());
+    }
+}
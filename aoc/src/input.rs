@@ -0,0 +1,124 @@
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `<root>/<year>/day-<day>/input.txt`, next to the day's crate.
+    #[default]
+    Bundled,
+    /// `<root>/inputs/<user>/<year>/day-<day>.txt`.
+    User(String),
+    /// An explicit file given with `--input`.
+    File(PathBuf),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Only files can be read for several days; stdin and `--input` hold a single puzzle.
+    pub fn is_single_day(&self) -> bool {
+        matches!(self, InputSource::File(_) | InputSource::Stdin)
+    }
+
+    /// The user whose recorded answers belong to this input, if any.
+    pub fn answers_user(&self) -> Option<&str> {
+        match self {
+            InputSource::Bundled => Some("default"),
+            InputSource::User(user) => Some(user),
+            InputSource::File(_) | InputSource::Stdin => None,
+        }
+    }
+
+    pub fn path(&self, root: &Path, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Bundled => Some(
+                root.join(year.to_string())
+                    .join(format!("day-{}", day))
+                    .join("input.txt"),
+            ),
+            InputSource::User(user) => Some(
+                root.join("inputs")
+                    .join(user)
+                    .join(year.to_string())
+                    .join(format!("day-{}.txt", day)),
+            ),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, root: &Path, year: u16, day: u8) -> io::Result<String> {
+        match self.path(root, year, day) {
+            Some(path) => std::fs::read_to_string(&path).map_err(|error| {
+                io::Error::new(
+                    error.kind(),
+                    format!("cannot read {}: {}", path.display(), error),
+                )
+            }),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// The workspace root, independent of the directory the binary is started from.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner crate to live inside the workspace")
+        .to_path_buf()
+}
+
+/// 64 bit FNV-1a of the input as hex, enough to tell which input produced a
+/// record without publishing the input itself.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_paths_per_source() {
+        let root = Path::new("/aoc");
+
+        assert_eq!(
+            InputSource::Bundled.path(root, 2023, 7),
+            Some(PathBuf::from("/aoc/2023/day-7/input.txt"))
+        );
+        assert_eq!(
+            InputSource::User("alice".to_string()).path(root, 2023, 7),
+            Some(PathBuf::from("/aoc/inputs/alice/2023/day-7.txt"))
+        );
+        assert_eq!(
+            InputSource::File(PathBuf::from("my.txt")).path(root, 2023, 7),
+            Some(PathBuf::from("my.txt"))
+        );
+        assert_eq!(InputSource::Stdin.path(root, 2023, 7), None);
+    }
+
+    #[test]
+    fn bundled_inputs_are_found_from_any_directory() {
+        let input = InputSource::Bundled.read(&workspace_root(), 2023, 1);
+
+        assert!(input.is_ok_and(|input| !input.is_empty()));
+    }
+
+    #[test]
+    fn hashes_inputs_with_fnv1a() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+}
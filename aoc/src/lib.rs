@@ -0,0 +1,8 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod input;
+pub mod output;
+pub mod registry;
+pub mod table;
+pub mod verify;
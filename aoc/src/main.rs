@@ -0,0 +1,327 @@
+use aoc::{
+    answers::Answers,
+    bench::{self, Change, History, Record},
+    cli::{Args, Command, USAGE},
+    input::{input_hash, workspace_root},
+    output::{self, Format},
+    registry,
+    verify::{render_table, Check, Outcome},
+};
+use aoc_core::{DynSolution, Error, Part};
+use std::{
+    io,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, SystemTime},
+};
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let solutions = registry::solutions()
+        .into_iter()
+        .filter(|solution| solution.year() == args.year)
+        .filter(|solution| args.day.is_none_or(|day| solution.day() == day))
+        .collect::<Vec<_>>();
+
+    if solutions.is_empty() {
+        eprintln!("error: no solution registered for the selected year/day");
+        return ExitCode::FAILURE;
+    }
+
+    let root = workspace_root();
+
+    let result = match args.command {
+        Command::Run => run(&args, &root, &solutions),
+        Command::Verify => verify(&args, &root, &solutions),
+        Command::Bench => bench(&args, &root, &solutions),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args, root: &Path, solutions: &[Box<dyn DynSolution>]) -> Result<ExitCode, String> {
+    let mut records = vec![];
+    let mut failed = false;
+
+    for solution in solutions {
+        let input = args
+            .input
+            .read(root, solution.year(), solution.day())
+            .map_err(|error| error.to_string())?;
+        let hash = input_hash(&input);
+
+        if args.format == Format::Text {
+            println!("{} day {}", solution.year(), solution.day());
+        }
+
+        for &part in &args.parts {
+            let outcome = match solution.run(&input, part) {
+                Ok(outcome) => outcome,
+                Err(error) => {
+                    eprintln!("{}", describe(solution.as_ref(), part, &input, &error));
+                    failed = true;
+
+                    if args.format == Format::Text {
+                        println!("Part {}: error", part);
+                    }
+
+                    records.push(output::Record {
+                        year: solution.year(),
+                        day: solution.day(),
+                        part: part.number(),
+                        answer: String::new(),
+                        duration_ns: 0,
+                        input_hash: hash.clone(),
+                        status: "error".to_string(),
+                    });
+                    continue;
+                }
+            };
+            let duration = outcome.parse_time + outcome.solve_time;
+
+            if args.format == Format::Text {
+                println!("Part {}: {} ({:?})", part, outcome.answer, duration);
+            }
+
+            records.push(output::Record {
+                year: solution.year(),
+                day: solution.day(),
+                part: part.number(),
+                answer: outcome.answer.to_string(),
+                duration_ns: duration.as_nanos(),
+                input_hash: hash.clone(),
+                status: "ok".to_string(),
+            });
+        }
+    }
+
+    print_records(args.format, &records);
+
+    if failed {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn verify(
+    args: &Args,
+    root: &Path,
+    solutions: &[Box<dyn DynSolution>],
+) -> Result<ExitCode, String> {
+    let user = args
+        .input
+        .answers_user()
+        .ok_or("`verify` needs a user to look answers up for")?;
+    let answers_path = args
+        .answers
+        .clone()
+        .unwrap_or_else(|| root.join("answers.toml"));
+    let answers = Answers::load(&answers_path)?;
+
+    let mut checks = vec![];
+    let mut records = vec![];
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let input = args.input.read(root, year, day);
+        let hash = input.as_deref().map(input_hash).unwrap_or_default();
+
+        for &part in &args.parts {
+            let (outcome, duration) = match &input {
+                Ok(input) => match solution.run(input, part) {
+                    Ok(outcome) => (
+                        Outcome::Answer(outcome.answer),
+                        outcome.parse_time + outcome.solve_time,
+                    ),
+                    Err(error) => {
+                        eprintln!("{}", describe(solution.as_ref(), part, input, &error));
+                        (Outcome::Error(error.to_string()), Duration::ZERO)
+                    }
+                },
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                    (Outcome::NoInput(error.to_string()), Duration::ZERO)
+                }
+                Err(error) => (Outcome::Error(error.to_string()), Duration::ZERO),
+            };
+            let check = Check {
+                year,
+                day,
+                part,
+                outcome,
+                expected: answers.get(user, year, day, part),
+            };
+
+            records.push(output::Record {
+                year,
+                day,
+                part: part.number(),
+                answer: match &check.outcome {
+                    Outcome::Answer(answer) => answer.to_string(),
+                    _ => String::new(),
+                },
+                duration_ns: duration.as_nanos(),
+                input_hash: hash.clone(),
+                status: check.status().to_string().to_lowercase(),
+            });
+            checks.push(check);
+        }
+    }
+
+    if args.format == Format::Text {
+        println!("{}", render_table(&checks));
+    } else {
+        print_records(args.format, &records);
+    }
+
+    if checks.iter().any(Check::is_failure) {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn bench(args: &Args, root: &Path, solutions: &[Box<dyn DynSolution>]) -> Result<ExitCode, String> {
+    let history_path = args
+        .history
+        .clone()
+        .unwrap_or_else(|| root.join("target").join("bench-history.csv"));
+    let history = History::load(&history_path)?;
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_err(|error| error.to_string())?
+        .as_secs();
+
+    let mut results = vec![];
+    let mut records = vec![];
+    let mut failures = 0;
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let input = match args.input.read(root, year, day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{} day {}: {}", year, day, error);
+                let status = match error.kind() {
+                    io::ErrorKind::NotFound => "missing",
+                    _ => "error",
+                };
+
+                for &part in &args.parts {
+                    records.push(failed_record(year, day, part, String::new(), status));
+                    failures += 1;
+                }
+                continue;
+            }
+        };
+        let hash = input_hash(&input);
+
+        for &part in &args.parts {
+            let report = match solution.bench(&input, part, &args.bench) {
+                Ok(report) => report,
+                Err(error) => {
+                    eprintln!("{}", describe(solution.as_ref(), part, &input, &error));
+                    records.push(failed_record(year, day, part, hash.clone(), "error"));
+                    failures += 1;
+                    continue;
+                }
+            };
+            let record = Record::new(
+                timestamp,
+                solution.year(),
+                solution.day(),
+                part,
+                hash.clone(),
+                &report,
+            );
+            let change = Change::between(&record, history.previous(&record), args.threshold);
+
+            records.push(output::Record {
+                year: record.year,
+                day: record.day,
+                part: part.number(),
+                answer: report.answer.to_string(),
+                duration_ns: record.total_median().as_nanos(),
+                input_hash: hash.clone(),
+                status: change.keyword().to_string(),
+            });
+            results.push((record, change));
+        }
+    }
+
+    if args.format == Format::Text {
+        println!("{}", bench::render_table(&results));
+    } else {
+        print_records(args.format, &records);
+    }
+
+    let history_records = results
+        .iter()
+        .map(|(record, _)| record.clone())
+        .collect::<Vec<_>>();
+    History::append(&history_path, &history_records)?;
+
+    let regressions = results
+        .iter()
+        .filter(|(_, change)| matches!(change, Change::Regression(_)))
+        .count();
+
+    if regressions > 0 {
+        eprintln!(
+            "{} part(s) regressed by more than {}% since the previous run",
+            regressions, args.threshold
+        );
+    }
+
+    if failures > 0 {
+        eprintln!("{} part(s) could not be benchmarked", failures);
+    }
+
+    if regressions > 0 || failures > 0 {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+/// The record of a part that produced no answer.
+fn failed_record(
+    year: u16,
+    day: u8,
+    part: Part,
+    input_hash: String,
+    status: &str,
+) -> output::Record {
+    output::Record {
+        year,
+        day,
+        part: part.number(),
+        answer: String::new(),
+        duration_ns: 0,
+        input_hash,
+        status: status.to_string(),
+    }
+}
+
+fn describe(solution: &dyn DynSolution, part: Part, input: &str, error: &Error) -> String {
+    output::describe_error(solution.year(), solution.day(), part, input, error)
+}
+
+fn print_records(format: Format, records: &[output::Record]) {
+    if let Some(rendered) = output::render(format, records) {
+        println!("{}", rendered);
+    }
+}
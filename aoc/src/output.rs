@@ -0,0 +1,168 @@
+use aoc_core::{Error, Part};
+use serde::Serialize;
+use std::str::FromStr;
+
+/// How results are written to stdout. Anything that is not a result goes
+/// to stderr so the structured formats stay parseable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+    /// A header line followed by one row per record.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Format, String> {
+        match format {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "invalid format `{}`, expected text, json or csv",
+                format
+            )),
+        }
+    }
+}
+
+/// A single part's result. `status` depends on the command: `ok`/`error`
+/// for runs, `pass`/`fail`/`missing`/`error` for `verify` and
+/// `new`/`unchanged`/`faster`/`regression`/`missing`/`error` for `bench`,
+/// where `duration_ns` is the median. Parts that fail have an empty `answer`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub duration_ns: u128,
+    pub input_hash: String,
+    pub status: String,
+}
+
+impl Record {
+    const CSV_HEADER: &'static str = "year,day,part,answer,duration_ns,input_hash,status";
+
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records to always serialise")
+    }
+
+    fn to_csv(&self) -> String {
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(&self.answer),
+            self.duration_ns.to_string(),
+            self.input_hash.clone(),
+            self.status.clone(),
+        ]
+        .join(",")
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders `records` in one of the structured formats, `None` for text,
+/// which every command prints its own way.
+pub fn render(format: Format, records: &[Record]) -> Option<String> {
+    match format {
+        Format::Text => None,
+        Format::Json => Some(
+            records
+                .iter()
+                .map(Record::to_json)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        Format::Csv => Some(
+            std::iter::once(Record::CSV_HEADER.to_string())
+                .chain(records.iter().map(Record::to_csv))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+    }
+}
+
+/// Describes a part that failed, underlining the offending input for parse
+/// errors.
+pub fn describe_error(year: u16, day: u8, part: Part, input: &str, error: &Error) -> String {
+    let message = format!("{} day {} part {}: {}", year, day, part, error);
+
+    match error {
+        Error::Parse(error) => format!("{}\n{}", message, error.snippet(input)),
+        Error::NoSolution(_) => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: &str) -> Record {
+        Record {
+            year: 2023,
+            day: 8,
+            part: 1,
+            answer: answer.to_string(),
+            duration_ns: 1500,
+            input_hash: "cbf29ce484222325".to_string(),
+            status: "ok".to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn renders_json_lines() {
+        assert_eq!(
+            render(Format::Json, &[record("42"), record("ZZZ")]).unwrap(),
+            "\
+{\"year\":2023,\"day\":8,\"part\":1,\"answer\":\"42\",\"duration_ns\":1500,\"input_hash\":\"cbf29ce484222325\",\"status\":\"ok\"}
+{\"year\":2023,\"day\":8,\"part\":1,\"answer\":\"ZZZ\",\"duration_ns\":1500,\"input_hash\":\"cbf29ce484222325\",\"status\":\"ok\"}"
+        );
+    }
+
+    #[test]
+    fn renders_csv_with_quoting() {
+        assert_eq!(
+            render(Format::Csv, &[record("42"), record("a,\"b\"")]).unwrap(),
+            "\
+year,day,part,answer,duration_ns,input_hash,status
+2023,8,1,42,1500,cbf29ce484222325,ok
+2023,8,1,\"a,\"\"b\"\"\",1500,cbf29ce484222325,ok"
+        );
+        assert_eq!(render(Format::Text, &[record("42")]), None);
+    }
+
+    #[test]
+    fn describes_parse_errors_with_a_snippet() {
+        let input = "32T3K 765\nT55X5 684";
+        let error = Error::Parse(aoc_core::ParseError::at(input, &input[13..14], "a card"));
+
+        assert_eq!(
+            describe_error(2023, 7, Part::One, input, &error),
+            "\
+2023 day 7 part 1: invalid input at line 2, column 4: expected a card, found `X`
+  |
+2 | T55X5 684
+  |    ^ expected a card"
+        );
+    }
+}
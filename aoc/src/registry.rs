@@ -0,0 +1,39 @@
+use aoc_core::{register, DynSolution};
+
+/// Every solved day, in calendar order.
+pub fn solutions() -> Vec<Box<dyn DynSolution>> {
+    vec![
+        register::<day_1::Day1>(),
+        register::<day_2::Day2>(),
+        register::<day_3::Day3>(),
+        register::<day_4::Day4>(),
+        register::<day_5::Day5>(),
+        register::<day_6::Day6>(),
+        register::<day_7::Day7>(),
+        register::<day_8::Day8>(),
+        register::<day_9::Day9>(),
+        register::<day_10::Day10>(),
+        register::<day_11::Day11>(),
+        register::<day_12::Day12>(),
+        register::<day_13::Day13>(),
+        register::<day_14::Day14>(),
+        register::<day_15::Day15>(),
+        register::<day_16::Day16>(),
+        register::<day_17::Day17>(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_once_in_order() {
+        let days = solutions()
+            .iter()
+            .map(|solution| (solution.year(), solution.day()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(days, (1..=17).map(|day| (2023, day)).collect::<Vec<_>>());
+    }
+}
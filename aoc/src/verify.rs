@@ -0,0 +1,194 @@
+use crate::table;
+use aoc_core::{Answer, Part};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Missing => "MISSING",
+            Status::Error => "ERROR",
+        })
+    }
+}
+
+/// What running a part produced.
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    Answer(Answer),
+    /// The day's input does not exist.
+    NoInput(String),
+    /// The input could not be read, or the solution failed on it.
+    Error(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub expected: Option<String>,
+}
+
+impl Check {
+    pub fn status(&self) -> Status {
+        let answer = match &self.outcome {
+            Outcome::Answer(answer) => answer,
+            Outcome::NoInput(_) => return Status::Missing,
+            Outcome::Error(_) => return Status::Error,
+        };
+
+        match &self.expected {
+            Some(expected) if *expected == answer.to_string() => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Missing,
+        }
+    }
+
+    /// Whether the check should fail the run. A part without a recorded
+    /// answer only does if it had no input to run on either.
+    pub fn is_failure(&self) -> bool {
+        match self.status() {
+            Status::Pass => false,
+            Status::Fail | Status::Error => true,
+            Status::Missing => matches!(self.outcome, Outcome::NoInput(_)),
+        }
+    }
+
+    /// The answer, or why there is none.
+    pub fn answer_text(&self) -> String {
+        match &self.outcome {
+            Outcome::Answer(answer) => answer.to_string(),
+            Outcome::NoInput(reason) | Outcome::Error(reason) => reason.clone(),
+        }
+    }
+}
+
+pub fn render_table(checks: &[Check]) -> String {
+    let rows = checks
+        .iter()
+        .map(|check| {
+            [
+                check.year.to_string(),
+                check.day.to_string(),
+                check.part.to_string(),
+                check.status().to_string(),
+                check.answer_text(),
+                check.expected.clone().unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    table::render(
+        ["Year", "Day", "Part", "Status", "Answer", "Expected"],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::Answers, input::workspace_root, input::InputSource, registry};
+
+    fn check(answer: u64, expected: Option<&str>) -> Check {
+        failure(Outcome::Answer(Answer::Unsigned(answer)), expected)
+    }
+
+    fn failure(outcome: Outcome, expected: Option<&str>) -> Check {
+        Check {
+            year: 2023,
+            day: 1,
+            part: Part::One,
+            outcome,
+            expected: expected.map(String::from),
+        }
+    }
+
+    #[test]
+    fn compares_answers_as_text() {
+        assert_eq!(check(42, Some("42")).status(), Status::Pass);
+        assert_eq!(check(42, Some("41")).status(), Status::Fail);
+        assert_eq!(check(42, None).status(), Status::Missing);
+    }
+
+    #[test]
+    fn missing_inputs_and_errors_fail_the_run() {
+        let no_input = failure(Outcome::NoInput("no input".to_string()), Some("42"));
+        let error = failure(Outcome::Error("no solution".to_string()), Some("42"));
+
+        assert_eq!(no_input.status(), Status::Missing);
+        assert_eq!(error.status(), Status::Error);
+        assert!(no_input.is_failure());
+        assert!(error.is_failure());
+        assert!(check(42, Some("41")).is_failure());
+        assert!(!check(42, Some("42")).is_failure());
+        assert!(!check(42, None).is_failure());
+    }
+
+    #[test]
+    fn renders_an_aligned_table() {
+        assert_eq!(
+            render_table(&[
+                check(42, Some("42")),
+                check(7, None),
+                failure(Outcome::Error("no solution".to_string()), Some("3")),
+            ]),
+            "\
+Year  Day  Part  Status   Answer       Expected
+2023  1    1     PASS     42           42
+2023  1    1     MISSING  7            -
+2023  1    1     ERROR    no solution  3"
+        );
+    }
+
+    #[test]
+    fn every_bundled_part_has_a_recorded_answer() {
+        let answers = Answers::load(&workspace_root().join("answers.toml")).unwrap();
+
+        for solution in registry::solutions() {
+            for part in Part::ALL {
+                let (year, day) = (solution.year(), solution.day());
+
+                assert!(
+                    answers.get("default", year, day, part).is_some(),
+                    "{year} day {day} part {part} has no answer in answers.toml"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bundled_inputs_match_recorded_answers() {
+        let root = workspace_root();
+        let answers = Answers::load(&root.join("answers.toml")).unwrap();
+        let solutions = registry::solutions();
+
+        for (year, day, part) in answers.recorded("default") {
+            let solution = solutions
+                .iter()
+                .find(|solution| (solution.year(), solution.day()) == (year, day))
+                .unwrap();
+            let input = InputSource::Bundled.read(&root, year, day).unwrap();
+
+            let check = Check {
+                year,
+                day,
+                part,
+                outcome: Outcome::Answer(solution.run(&input, part).unwrap().answer),
+                expected: answers.get("default", year, day, part),
+            };
+
+            assert_eq!(check.status(), Status::Pass, "{:?}", check);
+        }
+    }
+}